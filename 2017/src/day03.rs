@@ -105,7 +105,7 @@ fn get_distance(n: u32) -> u32 {
 
     let sqrt = (n as f32).sqrt();
     let d = sqrt.ceil() as u32;
-    let d = if d % 2 == 0 { d + 1 } else { d };
+    let d = if d.is_multiple_of(2) { d + 1 } else { d };
     let r = d / 2;
 
     let circle_min = (d - 2).pow(2) + 1;
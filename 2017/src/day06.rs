@@ -1,4 +1,53 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Memory {
+    banks: Vec<u32>,
+}
+
+impl From<&str> for Memory {
+    fn from(value: &str) -> Self {
+        Self {
+            banks: value
+                .split_ascii_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl Memory {
+    fn redistribute(&mut self) {
+        let (index, blocks) = self
+            .banks
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by_key(|(index, blocks)| (*blocks, usize::MAX - index))
+            .unwrap();
+
+        let len = self.banks.len();
+        let (share, remainder) = (blocks / len as u32, blocks as usize % len);
+
+        self.banks[index] = 0;
+        for offset in 1..=len {
+            self.banks[(index + offset) % len] += share + (offset <= remainder) as u32;
+        }
+    }
+
+    fn find_loop(mut self) -> (usize, usize) {
+        let mut seen = HashMap::new();
+
+        for steps in 0.. {
+            if let Some(first) = seen.insert(self.clone(), steps) {
+                return (steps, steps - first);
+            }
+            self.redistribute();
+        }
+
+        unreachable!()
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +56,55 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Memory::from(input).find_loop().0
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    Memory::from(input).find_loop().1
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "0 2 7 0
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 5;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 4;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_redistribute() {
+        let mut memory = Memory::from(INPUT);
+
+        for expected in [[2, 4, 1, 2], [3, 1, 2, 3], [0, 2, 3, 4], [1, 3, 4, 1]] {
+            memory.redistribute();
+            assert_eq!(memory.banks, expected);
+        }
+    }
+
+    #[test]
+    fn test_redistribute_large_bank() {
+        let mut memory = Memory::from("0 8 1");
+        memory.redistribute();
+        assert_eq!(memory.banks, [3, 2, 4]);
+
+        let mut memory = Memory::from("3 1 3");
+        memory.redistribute();
+        assert_eq!(memory.banks, [1, 2, 4]);
+    }
+
     // check_answers!(42, 42);
 }
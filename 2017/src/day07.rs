@@ -1,4 +1,124 @@
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+#[derive(Debug)]
+struct Program<'a> {
+    weight: u32,
+    children: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+struct Tower<'a> {
+    programs: HashMap<&'a str, Program<'a>>,
+    bottom: &'a str,
+}
+
+impl<'a> From<&'a str> for Tower<'a> {
+    fn from(value: &'a str) -> Self {
+        let programs = value
+            .lines()
+            .map(|line| {
+                let (program, children) = match line.split_once(" -> ") {
+                    Some((program, children)) => (program, children.split(", ").collect()),
+                    None => (line, Vec::new()),
+                };
+                let (name, weight) = program.split_once(" (").unwrap();
+                let weight = weight.strip_suffix(")").unwrap().parse().unwrap();
+
+                (name, Program { weight, children })
+            })
+            .collect::<HashMap<_, _>>();
+
+        let held = programs
+            .values()
+            .flat_map(|p| p.children.iter().copied())
+            .collect::<HashSet<_>>();
+        let bottom = programs
+            .keys()
+            .copied()
+            .find(|name| !held.contains(name))
+            .unwrap();
+
+        Self { programs, bottom }
+    }
+}
+
+impl Tower<'_> {
+    fn total_weights(&self) -> HashMap<&str, u32> {
+        let mut totals = HashMap::new();
+        self.total_weight(self.bottom, &mut totals);
+
+        totals
+    }
+
+    fn total_weight<'b>(&'b self, name: &'b str, totals: &mut HashMap<&'b str, u32>) -> u32 {
+        let program = &self.programs[name];
+        let total = program.weight
+            + program
+                .children
+                .iter()
+                .map(|child| self.total_weight(child, totals))
+                .sum::<u32>();
+        totals.insert(name, total);
+
+        total
+    }
+
+    fn is_balanced(&self, name: &str, totals: &HashMap<&str, u32>) -> bool {
+        let mut weights = self.programs[name].children.iter().map(|c| totals[c]);
+        let first = weights.next();
+
+        weights.all(|w| Some(w) == first)
+    }
+
+    fn correction(&self) -> Option<u32> {
+        let totals = self.total_weights();
+
+        self.correct(self.bottom, None, &totals)
+    }
+
+    /// Finds the weight the single wrong program must have. `expected` is the
+    /// total this subtree should weigh, if it is known from its siblings.
+    fn correct(
+        &self,
+        name: &str,
+        expected: Option<u32>,
+        totals: &HashMap<&str, u32>,
+    ) -> Option<u32> {
+        let program = &self.programs[name];
+        let children = &program.children;
+
+        if self.is_balanced(name, totals) {
+            return expected.map(|expected| program.weight + expected - totals[name]);
+        }
+
+        let target = if children.len() > 2 {
+            children
+                .iter()
+                .map(|c| totals[c])
+                .find(|w| children.iter().filter(|c| totals[*c] == *w).count() > 1)
+        } else {
+            expected.map(|expected| (expected - program.weight) / children.len() as u32)
+        };
+
+        let odd = match target {
+            Some(target) => children.iter().find(|c| totals[*c] != target),
+            // Two children and nothing above to compare against: only a child
+            // with an unbalanced subtree of its own can be the culprit.
+            None => children.iter().find(|c| !self.is_balanced(c, totals)),
+        }?;
+        let target = target.or_else(|| {
+            children
+                .iter()
+                .find(|c| *c != odd)
+                .map(|other| totals[other])
+        });
+
+        self.correct(odd, target, totals)
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +127,95 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    Tower::from(input).bottom.to_string()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> u32 {
+    Tower::from(input)
+        .correction()
+        .expect("Expected an unbalanced tower")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = "tknk";
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 60;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_total_weights() {
+        let tower = Tower::from(INPUT);
+        let totals = tower.total_weights();
+
+        assert_eq!(totals["ugml"], 251);
+        assert_eq!(totals["padx"], 243);
+        assert_eq!(totals["fwft"], 243);
+    }
+
+    #[test]
+    fn test_two_child_subtree() {
+        let input = "root (1) -> a, b, c
+a (4) -> x, y
+b (20)
+c (20)
+x (8)
+y (7)
+";
+
+        assert_eq!(solve_first_part(input), "root");
+        assert_eq!(solve_second_part(input), 8);
+    }
+
+    #[test]
+    fn test_two_child_bottom() {
+        let input = "root (1) -> a, b
+a (3) -> x, y, z
+b (12)
+x (3)
+y (3)
+z (4)
+";
+
+        assert_eq!(solve_second_part(input), 3);
+    }
+
+    #[test]
+    fn test_balanced_tower() {
+        let input = "root (1) -> a, b
+a (3)
+b (3)
+";
+
+        assert_eq!(Tower::from(input).correction(), None);
+    }
+
     // check_answers!(42, 42);
 }
@@ -1,4 +1,130 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+#[derive(Debug, PartialEq)]
+struct ParseError(String);
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParseError: {}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl FromStr for Comparison {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => Ok(Self::Less),
+            "<=" => Ok(Self::LessOrEqual),
+            ">" => Ok(Self::Greater),
+            ">=" => Ok(Self::GreaterOrEqual),
+            "==" => Ok(Self::Equal),
+            "!=" => Ok(Self::NotEqual),
+            op => Err(ParseError(format!("Unsupported comparison: '{op}'"))),
+        }
+    }
+}
+
+impl Comparison {
+    fn holds(&self, left: i32, right: i32) -> bool {
+        match self {
+            Self::Less => left < right,
+            Self::LessOrEqual => left <= right,
+            Self::Greater => left > right,
+            Self::GreaterOrEqual => left >= right,
+            Self::Equal => left == right,
+            Self::NotEqual => left != right,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    register: String,
+    delta: i32,
+    condition_register: String,
+    comparison: Comparison,
+    operand: i32,
+}
+
+fn parse_number(s: &str) -> Result<i32, ParseError> {
+    s.parse()
+        .or(Err(ParseError(format!("Invalid number: '{s}'"))))
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (action, condition) = s
+            .split_once(" if ")
+            .ok_or(ParseError(format!("Expected condition, got '{s}'")))?;
+
+        let [register, operation, amount] = action.split(' ').collect::<Vec<_>>()[..] else {
+            return Err(ParseError(format!("Invalid operation: '{action}'")));
+        };
+        let amount = parse_number(amount)?;
+        let delta = match operation {
+            "inc" => amount,
+            "dec" => -amount,
+            op => return Err(ParseError(format!("Unsupported operation: '{op}'"))),
+        };
+
+        let [condition_register, comparison, operand] =
+            condition.split(' ').collect::<Vec<_>>()[..]
+        else {
+            return Err(ParseError(format!("Invalid condition: '{condition}'")));
+        };
+
+        Ok(Self {
+            register: register.to_string(),
+            delta,
+            condition_register: condition_register.to_string(),
+            comparison: Comparison::from_str(comparison)?,
+            operand: parse_number(operand)?,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Cpu {
+    registers: HashMap<String, i32>,
+    highest: i32,
+}
+
+impl Cpu {
+    fn execute(&mut self, instruction: &Instruction) {
+        let value = *self
+            .registers
+            .entry(instruction.condition_register.clone())
+            .or_default();
+        let register = self
+            .registers
+            .entry(instruction.register.clone())
+            .or_default();
+
+        if !instruction.comparison.holds(value, instruction.operand) {
+            return;
+        }
+
+        *register += instruction.delta;
+        self.highest = self.highest.max(*register);
+    }
+
+    fn largest(&self) -> i32 {
+        self.registers.values().copied().max().unwrap_or(0)
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +133,110 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> i32 {
+    match evaluate(input) {
+        Ok(cpu) => cpu.largest(),
+        Err(error) => panic!("{error}"),
+    }
+}
+
+fn solve_second_part(input: &str) -> i32 {
+    match evaluate(input) {
+        Ok(cpu) => cpu.highest,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+fn evaluate(source_code: &str) -> Result<Cpu, ParseError> {
+    let instructions = parse(source_code)?;
+
+    let mut cpu = Cpu::default();
+    for instruction in &instructions {
+        cpu.execute(instruction);
+    }
+
+    Ok(cpu)
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn parse(source_code: &str) -> Result<Vec<Instruction>, ParseError> {
+    source_code.lines().map(Instruction::from_str).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 1;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 10;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_comparisons() {
+        let input = "a inc 1 if z < 1
+b inc 1 if z <= 0
+c inc 1 if z > -1
+d inc 1 if z >= 0
+e inc 1 if z == 0
+f inc 1 if z != 0
+";
+        let cpu = evaluate(input).unwrap();
+
+        for register in ["a", "b", "c", "d", "e"] {
+            assert_eq!(cpu.registers[register], 1);
+        }
+        assert_eq!(cpu.registers["f"], 0);
+    }
+
+    #[test]
+    fn test_all_registers_negative() {
+        let input = "a dec 1 if a == 0
+b dec 2 if a < 0
+";
+        let cpu = evaluate(input).unwrap();
+
+        assert_eq!(cpu.largest(), -1);
+        assert_eq!(cpu.highest, 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("a inc 1").unwrap_err(),
+            ParseError("Expected condition, got 'a inc 1'".to_string())
+        );
+        assert_eq!(
+            parse("a mul 2 if b > 1").unwrap_err(),
+            ParseError("Unsupported operation: 'mul'".to_string())
+        );
+        assert_eq!(
+            parse("a inc x if b > 1").unwrap_err(),
+            ParseError("Invalid number: 'x'".to_string())
+        );
+        assert_eq!(
+            parse("a inc 1 if b <> 1").unwrap_err(),
+            ParseError("Unsupported comparison: '<>'".to_string())
+        );
+        assert_eq!(
+            parse("a inc 1 if b >").unwrap_err(),
+            ParseError("Invalid condition: 'b >'".to_string())
+        );
+    }
+
     // check_answers!(42, 42);
 }
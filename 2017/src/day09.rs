@@ -1,5 +1,53 @@
 use std::fmt::Display;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Group,
+    Garbage,
+    Cancelled,
+}
+
+#[derive(Debug, Default)]
+struct Stream {
+    groups: usize,
+    score: usize,
+    garbage: usize,
+}
+
+impl From<&str> for Stream {
+    fn from(value: &str) -> Self {
+        let mut stream = Stream::default();
+        let mut state = State::Group;
+        let mut depth = 0;
+
+        for c in value.trim().chars() {
+            state = match (state, c) {
+                (State::Group, '{') => {
+                    depth += 1;
+                    State::Group
+                }
+                (State::Group, '}') => {
+                    stream.groups += 1;
+                    stream.score += depth;
+                    depth -= 1;
+                    State::Group
+                }
+                (State::Group, '<') => State::Garbage,
+                (State::Group, _) => State::Group,
+                (State::Garbage, '!') => State::Cancelled,
+                (State::Garbage, '>') => State::Group,
+                (State::Garbage, _) => {
+                    stream.garbage += 1;
+                    State::Garbage
+                }
+                (State::Cancelled, _) => State::Garbage,
+            };
+        }
+
+        stream
+    }
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,35 +55,62 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Stream::from(input).score
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    Stream::from(input).garbage
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "{{<a!>},{<a!>},{<a!>},{<ab>}}
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 3;
 
-        assert_eq!(answer, solve_first_part(INPUT))
+        assert_eq!(answer, solve_first_part(INPUT));
+        assert_eq!(1, solve_first_part("{}"));
+        assert_eq!(6, solve_first_part("{{{}}}"));
+        assert_eq!(5, solve_first_part("{{},{}}"));
+        assert_eq!(16, solve_first_part("{{{},{},{{}}}}"));
+        assert_eq!(1, solve_first_part("{<a>,<a>,<a>,<a>}"));
+        assert_eq!(9, solve_first_part("{{<ab>},{<ab>},{<ab>},{<ab>}}"));
+        assert_eq!(9, solve_first_part("{{<!!>},{<!!>},{<!!>},{<!!>}}"));
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 10;
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        assert_eq!(answer, solve_second_part("<{o\"i!a,<{i<a>"));
+        assert_eq!(0, solve_second_part("<>"));
+        assert_eq!(17, solve_second_part("<random characters>"));
+        assert_eq!(3, solve_second_part("<<<<>"));
+        assert_eq!(2, solve_second_part("<{!>}>"));
+        assert_eq!(0, solve_second_part("<!!>"));
+        assert_eq!(0, solve_second_part("<!!!>>"));
+    }
+
+    #[test]
+    fn test_groups() {
+        for (stream, groups) in [
+            ("{}", 1),
+            ("{{{}}}", 3),
+            ("{{},{}}", 3),
+            ("{{{},{},{{}}}}", 6),
+            ("{<{},{},{{}}>}", 1),
+            ("{<a>,<a>,<a>,<a>}", 1),
+            ("{{<a>},{<a>},{<a>},{<a>}}", 5),
+            ("{{<!>},{<!>},{<!>},{<a>}}", 2),
+        ] {
+            assert_eq!(Stream::from(stream).groups, groups, "{stream}");
+        }
     }
 
     // check_answers!(42, 42);
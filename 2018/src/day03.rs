@@ -1,12 +1,11 @@
 use std::fmt::Display;
 
+use crate::shared::rectangles::{self, Rectangle};
+
 #[derive(Debug)]
 struct Claim {
     id: u32,
-    x: usize,
-    y: usize,
-    width: usize,
-    height: usize,
+    area: Rectangle,
 }
 
 impl From<&str> for Claim {
@@ -18,21 +17,22 @@ impl From<&str> for Claim {
 
         Self {
             id: id.strip_prefix("#").unwrap().parse().unwrap(),
-            x: x.parse().unwrap(),
-            y: y.parse().unwrap(),
-            width: width.parse().unwrap(),
-            height: height.parse().unwrap(),
+            area: Rectangle {
+                x: x.parse().unwrap(),
+                y: y.parse().unwrap(),
+                width: width.parse().unwrap(),
+                height: height.parse().unwrap(),
+            },
         }
     }
 }
 
-impl Claim {
-    fn intersects(&self, other: &Claim) -> bool {
-        self.x < other.x + other.width
-            && self.x + self.width > other.x
-            && self.y < other.y + other.height
-            && self.y + self.height > other.y
-    }
+fn parse(input: &str) -> (Vec<u32>, Vec<Rectangle>) {
+    input
+        .lines()
+        .map(Claim::from)
+        .map(|claim| (claim.id, claim.area))
+        .unzip()
 }
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
@@ -42,34 +42,22 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(input: &str) -> usize {
-    let mut matrix = vec![vec![0; 1000]; 1000];
-
-    for claim in input.lines().map(Claim::from) {
-        (claim.x..claim.x + claim.width).for_each(|i| {
-            (claim.y..claim.y + claim.height).for_each(|j| {
-                matrix[j][i] += 1;
-            });
-        });
-    }
+fn solve_first_part(input: &str) -> i64 {
+    let (_, areas) = parse(input);
 
-    matrix.iter().flatten().filter(|c| **c > 1).count()
+    rectangles::overlap_area(&areas)
 }
 
 fn solve_second_part(input: &str) -> u32 {
-    let claims = input.lines().map(Claim::from);
-
-    for claim in claims.clone() {
-        if !claims
-            .clone()
-            .filter(|other| other.id != claim.id)
-            .any(|other| claim.intersects(&other))
-        {
-            return claim.id;
-        }
+    let (ids, areas) = parse(input);
+
+    match rectangles::isolated(&areas)[..] {
+        [index] => ids[index],
+        ref isolated => panic!(
+            "Expected exactly one intact claim, found {}",
+            isolated.len()
+        ),
     }
-
-    0
 }
 
 #[cfg(test)]
@@ -95,5 +83,16 @@ mod tests {
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_large_coordinates() {
+        let input = "#1 @ 0,0: 2000000x3
+#2 @ 1999999,0: 5x5
+#3 @ 5000000,5000000: 1x1
+";
+
+        assert_eq!(solve_first_part(input), 3);
+        assert_eq!(solve_second_part(input), 3);
+    }
+
     check_answers!(118322, 1178);
 }
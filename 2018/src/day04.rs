@@ -1,4 +1,128 @@
-use std::fmt::Display;
+use std::{cmp::Reverse, collections::HashMap, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Timestamp {
+    year: u32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+}
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        let (date, time) = value.split_once(' ').unwrap();
+        let mut date = date.split('-').map(|p| p.parse().unwrap());
+        let (hour, minute) = time.split_once(':').unwrap();
+
+        Self {
+            year: date.next().unwrap(),
+            month: date.next().unwrap(),
+            day: date.next().unwrap(),
+            hour: hour.parse().unwrap(),
+            minute: minute.parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    BeginsShift(u32),
+    FallsAsleep,
+    WakesUp,
+}
+
+impl From<&str> for Event {
+    fn from(value: &str) -> Self {
+        match value {
+            "falls asleep" => Self::FallsAsleep,
+            "wakes up" => Self::WakesUp,
+            _ => {
+                let id = value
+                    .strip_prefix("Guard #")
+                    .and_then(|v| v.strip_suffix(" begins shift"))
+                    .unwrap_or_else(|| panic!("Unexpected event: {value}"));
+                Self::BeginsShift(id.parse().unwrap())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Record {
+    timestamp: Timestamp,
+    event: Event,
+}
+
+impl From<&str> for Record {
+    fn from(value: &str) -> Self {
+        let (timestamp, event) = value.strip_prefix('[').unwrap().split_once("] ").unwrap();
+
+        Self {
+            timestamp: Timestamp::from(timestamp),
+            event: Event::from(event),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SleepLog {
+    minutes: HashMap<u32, [u32; 60]>,
+}
+
+impl From<&str> for SleepLog {
+    fn from(value: &str) -> Self {
+        let mut records = value.lines().map(Record::from).collect::<Vec<_>>();
+        records.sort_by_key(|r| r.timestamp);
+
+        let mut minutes: HashMap<u32, [u32; 60]> = HashMap::new();
+        let mut guard = None;
+        let mut asleep_since = None;
+
+        for record in records {
+            match record.event {
+                Event::BeginsShift(id) => {
+                    guard = Some(id);
+                    asleep_since = None;
+                }
+                Event::FallsAsleep => asleep_since = Some(record.timestamp.minute),
+                Event::WakesUp => {
+                    let id = guard.expect("No guard on duty");
+                    let since = asleep_since.take().expect("Guard was not asleep");
+                    let histogram = minutes.entry(id).or_insert([0; 60]);
+
+                    for minute in since..record.timestamp.minute {
+                        histogram[minute as usize] += 1;
+                    }
+                }
+            }
+        }
+
+        Self { minutes }
+    }
+}
+
+impl SleepLog {
+    fn most_frequent_minute(histogram: &[u32; 60]) -> (u32, u32) {
+        histogram
+            .iter()
+            .enumerate()
+            .map(|(minute, count)| (*count, minute as u32))
+            .max_by_key(|(count, minute)| (*count, Reverse(*minute)))
+            .unwrap()
+    }
+
+    fn strategy<K: Ord>(&self, key: impl Fn(&[u32; 60]) -> K) -> u32 {
+        let (id, histogram) = self
+            .minutes
+            .iter()
+            .max_by_key(|(_, histogram)| key(histogram))
+            .unwrap();
+        let (_, minute) = Self::most_frequent_minute(histogram);
+
+        id * minute
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +131,75 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> u32 {
+    SleepLog::from(input).strategy(|histogram| histogram.iter().sum::<u32>())
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> u32 {
+    SleepLog::from(input).strategy(|histogram| SleepLog::most_frequent_minute(histogram).0)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 240;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 4455;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_shuffled_log() {
+        let lines = INPUT.lines().collect::<Vec<_>>();
+
+        let reversed = lines.iter().rev().copied().collect::<Vec<_>>().join("\n");
+        assert_eq!(solve_first_part(&reversed), 240);
+        assert_eq!(solve_second_part(&reversed), 4455);
+
+        let mut interleaved = lines.clone();
+        interleaved.sort_by_key(|line| line.chars().rev().collect::<String>());
+        let interleaved = interleaved.join("\n");
+        assert_eq!(solve_first_part(&interleaved), 240);
+        assert_eq!(solve_second_part(&interleaved), 4455);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let log = SleepLog::from(INPUT);
+
+        assert_eq!(log.minutes[&10].iter().sum::<u32>(), 50);
+        assert_eq!(log.minutes[&10][24], 2);
+        assert_eq!(log.minutes[&99].iter().sum::<u32>(), 30);
+        assert_eq!(log.minutes[&99][45], 3);
+    }
+
     // check_answers!(42, 42);
 }
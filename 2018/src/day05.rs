@@ -1,5 +1,23 @@
 use std::fmt::Display;
 
+fn reacts(a: u8, b: u8) -> bool {
+    a != b && a.eq_ignore_ascii_case(&b)
+}
+
+fn reduce(units: impl Iterator<Item = u8>) -> Vec<u8> {
+    let mut stack = Vec::new();
+
+    for unit in units {
+        if stack.last().is_some_and(|top| reacts(*top, unit)) {
+            stack.pop();
+        } else {
+            stack.push(unit);
+        }
+    }
+
+    stack
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,36 +25,113 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    reduce(input.trim().bytes()).len()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    let polymer = reduce(input.trim().bytes());
+
+    (b'a'..=b'z')
+        .map(|removed| {
+            let units = polymer
+                .iter()
+                .copied()
+                .filter(|unit| unit.to_ascii_lowercase() != removed);
+            reduce(units).len()
+        })
+        .min()
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::random::XorShift;
+    use std::time::Instant;
 
-    const INPUT: &str = "
+    const INPUT: &str = "dabAcCaCBAcCcaDA
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 10;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 4;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_reduce() {
+        assert_eq!(reduce("aA".bytes()), b"");
+        assert_eq!(reduce("abBA".bytes()), b"");
+        assert_eq!(reduce("abAB".bytes()), b"abAB");
+        assert_eq!(reduce("aabAAB".bytes()), b"aabAAB");
+        assert_eq!(reduce("dabAcCaCBAcCcaDA".bytes()), b"dabCBAcaDA");
+    }
+
+    fn reduce_naive(polymer: &str) -> String {
+        let mut polymer = polymer.to_string();
+
+        loop {
+            let bytes = polymer.as_bytes();
+            let Some(index) = (1..bytes.len()).find(|i| reacts(bytes[i - 1], bytes[*i])) else {
+                return polymer;
+            };
+            polymer.replace_range(index - 1..=index, "");
+        }
+    }
+
+    fn solve_second_part_naive(input: &str) -> usize {
+        (b'a'..=b'z')
+            .map(|removed| {
+                let polymer = input
+                    .trim()
+                    .chars()
+                    .filter(|unit| unit.to_ascii_lowercase() as u8 != removed)
+                    .collect::<String>();
+                reduce_naive(&polymer).len()
+            })
+            .min()
+            .unwrap()
+    }
+
+    // Timings are only printed; run with
+    // `cargo test --release bench_second_part -- --ignored --nocapture`.
+    #[ignore]
+    #[test]
+    fn bench_second_part() {
+        let mut rng = XorShift::new(0x2018);
+        let polymer = (0..50_000)
+            .map(|_| {
+                let seed = rng.next_u64();
+                let unit = (b'a' + (seed % 26) as u8) as char;
+                if seed >> 32 & 1 == 0 {
+                    unit
+                } else {
+                    unit.to_ascii_uppercase()
+                }
+            })
+            .collect::<String>();
+        assert!(solve_first_part(&polymer) > 40_000);
+
+        let start = Instant::now();
+        let naive = solve_second_part_naive(&polymer);
+        let naive_time = start.elapsed();
+
+        let start = Instant::now();
+        let stack = solve_second_part(&polymer);
+        let stack_time = start.elapsed();
+
+        println!("naive: {naive_time:?}, stack: {stack_time:?}");
+        assert_eq!(naive, stack);
+    }
+
     // check_answers!(42, 42);
 }
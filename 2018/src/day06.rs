@@ -1,4 +1,79 @@
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl From<&str> for Point {
+    fn from(value: &str) -> Self {
+        let (x, y) = value.split_once(", ").unwrap();
+
+        Self {
+            x: x.parse().unwrap(),
+            y: y.parse().unwrap(),
+        }
+    }
+}
+
+impl Point {
+    fn distance(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+#[derive(Debug)]
+struct Bounds {
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+}
+
+impl Bounds {
+    fn new(points: &[Point], margin: i32) -> Self {
+        Self {
+            min_x: points.iter().map(|p| p.x).min().unwrap() - margin,
+            max_x: points.iter().map(|p| p.x).max().unwrap() + margin,
+            min_y: points.iter().map(|p| p.y).min().unwrap() - margin,
+            max_y: points.iter().map(|p| p.y).max().unwrap() + margin,
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.min_y..=self.max_y)
+            .flat_map(|y| (self.min_x..=self.max_x).map(move |x| Point { x, y }))
+    }
+
+    fn on_edge(&self, point: &Point) -> bool {
+        point.x == self.min_x
+            || point.x == self.max_x
+            || point.y == self.min_y
+            || point.y == self.max_y
+    }
+}
+
+fn nearest(points: &[Point], cell: &Point) -> Option<usize> {
+    let mut best = None;
+    let mut best_distance = i32::MAX;
+
+    for (index, point) in points.iter().enumerate() {
+        let distance = point.distance(cell);
+        if distance < best_distance {
+            best = Some(index);
+            best_distance = distance;
+        } else if distance == best_distance {
+            best = None;
+        }
+    }
+
+    best
+}
+
+fn parse(input: &str) -> Vec<Point> {
+    input.lines().map(Point::from).collect()
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,35 +82,78 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    let points = parse(input);
+    let bounds = Bounds::new(&points, 0);
+
+    let mut areas = vec![0; points.len()];
+    let mut infinite = HashSet::new();
+
+    for cell in bounds.points() {
+        if let Some(owner) = nearest(&points, &cell) {
+            areas[owner] += 1;
+            if bounds.on_edge(&cell) {
+                infinite.insert(owner);
+            }
+        }
+    }
+
+    areas
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !infinite.contains(index))
+        .map(|(_, area)| area)
+        .max()
+        .unwrap()
+}
+
+fn solve_second_part(input: &str) -> usize {
+    safe_region_size(input, 10000)
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn safe_region_size(input: &str, threshold: i32) -> usize {
+    let points = parse(input);
+    let bounds = Bounds::new(&points, threshold / points.len() as i32 + 1);
+
+    bounds
+        .points()
+        .filter(|cell| points.iter().map(|p| p.distance(cell)).sum::<i32>() < threshold)
+        .count()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 17;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 16;
+
+        assert_eq!(answer, safe_region_size(INPUT, 32))
+    }
+
+    #[test]
+    fn test_nearest() {
+        let points = parse(INPUT);
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        assert_eq!(nearest(&points, &Point { x: 0, y: 0 }), Some(0));
+        assert_eq!(nearest(&points, &Point { x: 5, y: 0 }), None);
+        assert_eq!(nearest(&points, &Point { x: 5, y: 2 }), Some(4));
     }
 
     // check_answers!(42, 42);
@@ -1,4 +1,167 @@
-use std::fmt::Display;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+#[derive(Debug)]
+struct Instructions {
+    requirements: BTreeMap<char, BTreeSet<char>>,
+}
+
+impl From<&str> for Instructions {
+    fn from(value: &str) -> Self {
+        let mut requirements: BTreeMap<char, BTreeSet<char>> = BTreeMap::new();
+
+        for line in value.lines() {
+            let mut words = line.split_ascii_whitespace();
+            let before = words.nth(1).unwrap().chars().next().unwrap();
+            let after = words.nth(5).unwrap().chars().next().unwrap();
+
+            requirements.entry(before).or_default();
+            requirements.entry(after).or_default().insert(before);
+        }
+
+        Self { requirements }
+    }
+}
+
+impl Instructions {
+    fn order(&self) -> String {
+        let mut done = BTreeSet::new();
+        let mut order = String::new();
+
+        loop {
+            let Some(step) = self.available(&done, &BTreeSet::new()).next() else {
+                break;
+            };
+
+            done.insert(step);
+            order.push(step);
+        }
+
+        order
+    }
+
+    fn schedule(&self, workers: usize, base_duration: u32) -> Schedule {
+        let mut schedule = Schedule {
+            workers,
+            assignments: Vec::new(),
+        };
+        let mut in_progress: Vec<Option<(char, u32)>> = vec![None; workers];
+        let mut started = BTreeSet::new();
+        let mut done = BTreeSet::new();
+        let mut time = 0;
+
+        loop {
+            let mut available = self
+                .available(&done, &started)
+                .collect::<Vec<_>>()
+                .into_iter();
+
+            for (worker, slot) in in_progress.iter_mut().enumerate() {
+                if slot.is_some() {
+                    continue;
+                }
+                let Some(step) = available.next() else {
+                    break;
+                };
+
+                let end = time + base_duration + (step as u8 - b'A' + 1) as u32;
+                *slot = Some((step, end));
+                started.insert(step);
+                schedule.assignments.push(Assignment {
+                    step,
+                    worker,
+                    start: time,
+                    end,
+                });
+            }
+
+            let Some(next) = in_progress.iter().flatten().map(|(_, end)| *end).min() else {
+                break;
+            };
+
+            time = next;
+            for slot in in_progress.iter_mut() {
+                if let Some((step, end)) = *slot {
+                    if end == time {
+                        done.insert(step);
+                        *slot = None;
+                    }
+                }
+            }
+        }
+
+        schedule
+    }
+
+    fn available<'a>(
+        &'a self,
+        done: &'a BTreeSet<char>,
+        started: &'a BTreeSet<char>,
+    ) -> impl Iterator<Item = char> + 'a {
+        self.requirements
+            .iter()
+            .filter(|(step, _)| !done.contains(step) && !started.contains(step))
+            .filter(|(_, requirements)| requirements.is_subset(done))
+            .map(|(step, _)| *step)
+    }
+}
+
+#[derive(Debug)]
+struct Assignment {
+    step: char,
+    worker: usize,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug)]
+struct Schedule {
+    workers: usize,
+    assignments: Vec<Assignment>,
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Second")?;
+        for worker in 1..=self.workers {
+            write!(f, "   Worker {worker}")?;
+        }
+        writeln!(f, "   Done")?;
+
+        let mut finished = self.assignments.iter().collect::<Vec<_>>();
+        finished.sort_by_key(|a| (a.end, a.step));
+
+        for second in 0..=self.duration() {
+            let mut line = format!("{second:>6}");
+
+            for worker in 0..self.workers {
+                let step = self
+                    .assignments
+                    .iter()
+                    .find(|a| a.worker == worker && a.start <= second && second < a.end)
+                    .map_or('.', |a| a.step);
+                line.push_str(&format!("   {step:^8}"));
+            }
+
+            let done = finished
+                .iter()
+                .filter(|a| a.end <= second)
+                .map(|a| a.step)
+                .collect::<String>();
+            writeln!(f, "{}", format!("{line}   {done}").trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Schedule {
+    fn duration(&self) -> u32 {
+        self.assignments.iter().map(|a| a.end).max().unwrap_or(0)
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,35 +170,72 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    Instructions::from(input).order()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> u32 {
+    assembly_time(input, 5, 60)
+}
+
+fn assembly_time(input: &str, workers: usize, base_duration: u32) -> u32 {
+    Instructions::from(input)
+        .schedule(workers, base_duration)
+        .duration()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = "CABDFE";
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 15;
+
+        assert_eq!(answer, assembly_time(INPUT, 2, 0))
+    }
+
+    #[test]
+    fn test_timeline() {
+        let schedule = Instructions::from(INPUT).schedule(2, 0);
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        assert_eq!(
+            schedule.to_string(),
+            "Second   Worker 1   Worker 2   Done
+     0      C          .
+     1      C          .
+     2      C          .
+     3      A          F       C
+     4      B          F       CA
+     5      B          F       CA
+     6      D          F       CAB
+     7      D          F       CAB
+     8      D          F       CAB
+     9      D          .       CABF
+    10      E          .       CABFD
+    11      E          .       CABFD
+    12      E          .       CABFD
+    13      E          .       CABFD
+    14      E          .       CABFD
+    15      .          .       CABFDE
+"
+        );
     }
 
     // check_answers!(42, 42);
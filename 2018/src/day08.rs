@@ -1,4 +1,110 @@
-use std::fmt::Display;
+use std::{fmt::Display, mem};
+
+#[derive(Debug)]
+struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    children_left: usize,
+    metadata_len: usize,
+    children: Vec<Node>,
+}
+
+impl Frame {
+    fn read(numbers: &mut impl Iterator<Item = usize>) -> Self {
+        Self {
+            children_left: numbers.next().expect("Expected children count"),
+            metadata_len: numbers.next().expect("Expected metadata count"),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl From<&str> for Node {
+    fn from(value: &str) -> Self {
+        let mut numbers = value
+            .split_ascii_whitespace()
+            .map(|n| n.parse::<usize>().unwrap());
+        let mut stack = vec![Frame::read(&mut numbers)];
+
+        loop {
+            let frame = stack.last_mut().unwrap();
+            if frame.children_left > 0 {
+                frame.children_left -= 1;
+                stack.push(Frame::read(&mut numbers));
+                continue;
+            }
+
+            let frame = stack.pop().unwrap();
+            let metadata = (0..frame.metadata_len)
+                .map(|_| numbers.next().expect("Expected metadata entry"))
+                .collect();
+            let node = Node {
+                children: frame.children,
+                metadata,
+            };
+
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => return node,
+            }
+        }
+    }
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut stack = mem::take(&mut self.children);
+
+        while let Some(mut node) = stack.pop() {
+            stack.append(&mut node.children);
+        }
+    }
+}
+
+impl Node {
+    fn metadata_sum(&self) -> usize {
+        let mut stack = vec![self];
+        let mut sum = 0;
+
+        while let Some(node) = stack.pop() {
+            sum += node.metadata.iter().sum::<usize>();
+            stack.extend(node.children.iter());
+        }
+
+        sum
+    }
+
+    fn value(&self) -> usize {
+        let mut stack = vec![(self, false)];
+        let mut values = Vec::new();
+
+        while let Some((node, expanded)) = stack.pop() {
+            if !expanded {
+                stack.push((node, true));
+                stack.extend(node.children.iter().rev().map(|child| (child, false)));
+                continue;
+            }
+
+            let children = values.split_off(values.len() - node.children.len());
+            let value = if children.is_empty() {
+                node.metadata.iter().sum()
+            } else {
+                node.metadata
+                    .iter()
+                    .filter_map(|index| index.checked_sub(1).and_then(|i| children.get(i)))
+                    .sum()
+            };
+
+            values.push(value);
+        }
+
+        values.pop().unwrap()
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +113,53 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Node::from(input).metadata_sum()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    Node::from(input).value()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 138;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 66;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_tree() {
+        let root = Node::from(INPUT);
+
+        assert_eq!(root.metadata, vec![1, 1, 2]);
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children[0].metadata, vec![10, 11, 12]);
+        assert_eq!(root.children[1].children[0].metadata, vec![99]);
+    }
+
+    #[test]
+    fn test_deep_tree() {
+        let depth = 200_000;
+        let input = "1 1 ".repeat(depth) + "0 1 7" + &" 1".repeat(depth);
+
+        assert_eq!(solve_first_part(&input), depth + 7);
+        assert_eq!(solve_second_part(&input), 7);
+    }
+
     // check_answers!(42, 42);
 }
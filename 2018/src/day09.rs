@@ -1,5 +1,96 @@
 use std::fmt::Display;
 
+#[derive(Debug)]
+struct Game {
+    players: usize,
+    last_marble: usize,
+}
+
+impl From<&str> for Game {
+    fn from(value: &str) -> Self {
+        let words = value.split_ascii_whitespace().collect::<Vec<_>>();
+
+        Self {
+            players: words[0].parse().unwrap(),
+            last_marble: words[6].parse().unwrap(),
+        }
+    }
+}
+
+impl Game {
+    fn high_score(&self) -> u64 {
+        let mut ring = Ring::new(self.last_marble + 1);
+        let mut scores = vec![0; self.players];
+
+        for marble in 1..=self.last_marble {
+            if marble % 23 == 0 {
+                ring.rotate(-7);
+                scores[marble % self.players] += (marble + ring.remove()) as u64;
+            } else {
+                ring.rotate(1);
+                ring.insert(marble);
+            }
+        }
+
+        scores.into_iter().max().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    previous: usize,
+    next: usize,
+}
+
+#[derive(Debug)]
+struct Ring {
+    nodes: Vec<Node>,
+    current: usize,
+}
+
+impl Ring {
+    fn new(capacity: usize) -> Self {
+        let nodes = vec![
+            Node {
+                previous: 0,
+                next: 0,
+            };
+            capacity
+        ];
+
+        Self { nodes, current: 0 }
+    }
+
+    fn rotate(&mut self, steps: isize) {
+        for _ in 0..steps.unsigned_abs() {
+            let node = self.nodes[self.current];
+            self.current = if steps > 0 { node.next } else { node.previous };
+        }
+    }
+
+    fn insert(&mut self, marble: usize) {
+        let next = self.nodes[self.current].next;
+        self.nodes[marble] = Node {
+            previous: self.current,
+            next,
+        };
+        self.nodes[self.current].next = marble;
+        self.nodes[next].previous = marble;
+        self.current = marble;
+    }
+
+    fn remove(&mut self) -> usize {
+        let removed = self.current;
+        let Node { previous, next } = self.nodes[removed];
+
+        self.nodes[previous].next = next;
+        self.nodes[next].previous = previous;
+        self.current = next;
+
+        removed
+    }
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,36 +98,61 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> u64 {
+    Game::from(input).high_score()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> u64 {
+    let mut game = Game::from(input);
+    game.last_marble *= 100;
+
+    game.high_score()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "10 players; last marble is worth 1618 points
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 8317;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 74765078;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_high_scores() {
+        assert_eq!(
+            solve_first_part("9 players; last marble is worth 25 points"),
+            32
+        );
+        assert_eq!(
+            solve_first_part("13 players; last marble is worth 7999 points"),
+            146373
+        );
+        assert_eq!(
+            solve_first_part("17 players; last marble is worth 1104 points"),
+            2764
+        );
+        assert_eq!(
+            solve_first_part("21 players; last marble is worth 6111 points"),
+            54718
+        );
+        assert_eq!(
+            solve_first_part("30 players; last marble is worth 5807 points"),
+            37305
+        );
+    }
+
     // check_answers!(42, 42);
 }
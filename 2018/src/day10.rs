@@ -1,5 +1,245 @@
 use std::fmt::Display;
 
+const LETTER_WIDTH: usize = 6;
+const LETTER_HEIGHT: usize = 10;
+const LETTER_SPACING: usize = 2;
+
+const LETTERS: [(char, [&str; LETTER_HEIGHT]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+#[derive(Debug, Clone, Copy)]
+struct Star {
+    x: i64,
+    y: i64,
+    dx: i64,
+    dy: i64,
+}
+
+impl From<&str> for Star {
+    fn from(value: &str) -> Self {
+        let mut numbers = value
+            .split(['<', '>', ','])
+            .filter_map(|part| part.trim().parse().ok());
+
+        Self {
+            x: numbers.next().unwrap(),
+            y: numbers.next().unwrap(),
+            dx: numbers.next().unwrap(),
+            dy: numbers.next().unwrap(),
+        }
+    }
+}
+
+impl Star {
+    fn position_at(&self, time: i64) -> (i64, i64) {
+        (self.x + self.dx * time, self.y + self.dy * time)
+    }
+}
+
+#[derive(Debug)]
+struct Sky {
+    stars: Vec<Star>,
+}
+
+impl From<&str> for Sky {
+    fn from(value: &str) -> Self {
+        Self {
+            stars: value.lines().map(Star::from).collect(),
+        }
+    }
+}
+
+impl Sky {
+    fn height_at(&self, time: i64) -> i64 {
+        let ys = self.stars.iter().map(|s| s.position_at(time).1);
+        ys.clone().max().unwrap() - ys.min().unwrap()
+    }
+
+    fn message_time(&self) -> i64 {
+        let slowest = self.stars.iter().min_by_key(|s| s.dy).unwrap();
+        let fastest = self.stars.iter().max_by_key(|s| s.dy).unwrap();
+
+        let mut time = if fastest.dy == slowest.dy {
+            0
+        } else {
+            (slowest.y - fastest.y) / (fastest.dy - slowest.dy)
+        };
+
+        while self.height_at(time + 1) < self.height_at(time) {
+            time += 1;
+        }
+        while self.height_at(time - 1) < self.height_at(time) {
+            time -= 1;
+        }
+
+        time
+    }
+
+    fn render(&self, time: i64) -> Vec<Vec<bool>> {
+        let positions = self
+            .stars
+            .iter()
+            .map(|s| s.position_at(time))
+            .collect::<Vec<_>>();
+
+        let min_x = positions.iter().map(|p| p.0).min().unwrap();
+        let max_x = positions.iter().map(|p| p.0).max().unwrap();
+        let min_y = positions.iter().map(|p| p.1).min().unwrap();
+        let max_y = positions.iter().map(|p| p.1).max().unwrap();
+
+        let mut canvas =
+            vec![vec![false; (max_x - min_x + 1) as usize]; (max_y - min_y + 1) as usize];
+        for (x, y) in positions {
+            canvas[(y - min_y) as usize][(x - min_x) as usize] = true;
+        }
+
+        canvas
+    }
+}
+
+fn draw(canvas: &[Vec<bool>]) -> String {
+    canvas
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|lit| if *lit { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+fn recognize(canvas: &[Vec<bool>]) -> Option<String> {
+    if canvas.len() != LETTER_HEIGHT {
+        return None;
+    }
+
+    let width = canvas[0].len();
+    let glyphs = draw(canvas);
+    let rows = glyphs.lines().collect::<Vec<_>>();
+
+    (0..width)
+        .step_by(LETTER_WIDTH + LETTER_SPACING)
+        .map(|start| {
+            let end = (start + LETTER_WIDTH).min(width);
+            let glyph = rows
+                .iter()
+                .map(|row| format!("{:.<LETTER_WIDTH$}", &row[start..end]))
+                .collect::<Vec<_>>();
+
+            LETTERS
+                .iter()
+                .find(|(_, letter)| letter.iter().eq(glyph.iter()))
+                .map(|(char, _)| *char)
+        })
+        .collect()
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,36 +247,118 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    let sky = Sky::from(input);
+    let canvas = sky.render(sky.message_time());
+
+    recognize(&canvas).unwrap_or_else(|| draw(&canvas))
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> i64 {
+    Sky::from(input).message_time()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
-
-        assert_eq!(answer, solve_first_part(INPUT))
+        assert_eq!(
+            solve_first_part(INPUT),
+            "#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+"
+        );
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 3;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_recognize() {
+        let message = "HAZEL";
+        let time = 10345;
+
+        let input = message
+            .chars()
+            .enumerate()
+            .flat_map(|(index, char)| {
+                let (_, glyph) = LETTERS.iter().find(|(c, _)| *c == char).unwrap();
+                let offset = index * (LETTER_WIDTH + LETTER_SPACING);
+
+                glyph.iter().enumerate().flat_map(move |(y, row)| {
+                    row.chars()
+                        .enumerate()
+                        .filter(|(_, c)| *c == '#')
+                        .map(move |(x, _)| (x + offset, y))
+                })
+            })
+            .enumerate()
+            .map(|(index, (x, y))| {
+                let dx = (index as i64 * 3) % 7 - 3;
+                let dy = (index as i64 * 5) % 9 - 4;
+                let x = x as i64 - dx * time;
+                let y = y as i64 - dy * time;
+
+                format!("position=<{x}, {y}> velocity=<{dx}, {dy}>\n")
+            })
+            .collect::<String>();
+
+        assert_eq!(solve_first_part(&input), message);
+        assert_eq!(solve_second_part(&input), time);
+    }
+
+    #[test]
+    fn test_recognize_unknown_glyph() {
+        let mut canvas = vec![vec![false; LETTER_WIDTH]; LETTER_HEIGHT];
+        canvas[0][0] = true;
+
+        assert_eq!(recognize(&canvas), None);
+    }
+
     // check_answers!(42, 42);
 }
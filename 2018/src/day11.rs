@@ -1,5 +1,49 @@
 use std::fmt::Display;
 
+const GRID_SIZE: usize = 300;
+
+#[derive(Debug)]
+struct Grid {
+    sums: Vec<Vec<i32>>,
+}
+
+impl Grid {
+    fn new(serial: i32) -> Self {
+        let mut sums = vec![vec![0; GRID_SIZE + 1]; GRID_SIZE + 1];
+
+        for y in 1..=GRID_SIZE {
+            for x in 1..=GRID_SIZE {
+                sums[y][x] = power_level(x, y, serial) + sums[y - 1][x] + sums[y][x - 1]
+                    - sums[y - 1][x - 1];
+            }
+        }
+
+        Self { sums }
+    }
+
+    fn square_power(&self, x: usize, y: usize, size: usize) -> i32 {
+        let (x0, y0) = (x - 1, y - 1);
+        let (x1, y1) = (x0 + size, y0 + size);
+
+        self.sums[y1][x1] - self.sums[y0][x1] - self.sums[y1][x0] + self.sums[y0][x0]
+    }
+
+    fn best_square(&self, size: usize) -> (i32, usize, usize) {
+        (1..=GRID_SIZE - size + 1)
+            .flat_map(|y| (1..=GRID_SIZE - size + 1).map(move |x| (x, y)))
+            .map(|(x, y)| (self.square_power(x, y, size), x, y))
+            .max_by_key(|(power, _, _)| *power)
+            .unwrap()
+    }
+}
+
+fn power_level(x: usize, y: usize, serial: i32) -> i32 {
+    let rack_id = x as i32 + 10;
+    let power = (rack_id * y as i32 + serial) * rack_id;
+
+    power / 100 % 10 - 5
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,35 +51,63 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    let grid = Grid::new(input.trim().parse().unwrap());
+    let (_, x, y) = grid.best_square(3);
+
+    format!("{x},{y}")
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> String {
+    let grid = Grid::new(input.trim().parse().unwrap());
+    let (_, x, y, size) = (1..=GRID_SIZE)
+        .map(|size| {
+            let (power, x, y) = grid.best_square(size);
+            (power, x, y, size)
+        })
+        .max_by_key(|(power, _, _, _)| *power)
+        .unwrap();
+
+    format!("{x},{y},{size}")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "18
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = "33,45";
 
-        assert_eq!(answer, solve_first_part(INPUT))
+        assert_eq!(answer, solve_first_part(INPUT));
+        assert_eq!("21,61", solve_first_part("42"));
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = "90,269,16";
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        assert_eq!(answer, solve_second_part(INPUT));
+        assert_eq!("232,251,12", solve_second_part("42"));
+    }
+
+    #[test]
+    fn test_power_level() {
+        assert_eq!(power_level(3, 5, 8), 4);
+        assert_eq!(power_level(122, 79, 57), -5);
+        assert_eq!(power_level(217, 196, 39), 0);
+        assert_eq!(power_level(101, 153, 71), 4);
+    }
+
+    #[test]
+    fn test_square_power() {
+        assert_eq!(Grid::new(18).square_power(33, 45, 3), 29);
+        assert_eq!(Grid::new(42).square_power(21, 61, 3), 30);
+        assert_eq!(Grid::new(18).square_power(90, 269, 16), 113);
+        assert_eq!(Grid::new(42).square_power(232, 251, 12), 119);
     }
 
     // check_answers!(42, 42);
@@ -1,5 +1,92 @@
 use std::fmt::Display;
 
+use crate::shared::extrapolation::extrapolate_steady_state;
+
+#[derive(Debug, Clone)]
+struct Pots {
+    plants: Vec<bool>,
+    offset: i64,
+}
+
+impl From<&str> for Pots {
+    fn from(value: &str) -> Self {
+        Self {
+            plants: value.chars().map(|c| c == '#').collect(),
+            offset: 0,
+        }
+        .trimmed()
+    }
+}
+
+impl Display for Pots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for plant in &self.plants {
+            write!(f, "{}", if *plant { '#' } else { '.' })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Pots {
+    fn trimmed(mut self) -> Self {
+        let Some(first) = self.plants.iter().position(|p| *p) else {
+            return Self {
+                plants: Vec::new(),
+                offset: 0,
+            };
+        };
+        let last = self.plants.iter().rposition(|p| *p).unwrap();
+
+        self.plants.truncate(last + 1);
+        self.plants.drain(..first);
+        self.offset += first as i64;
+
+        self
+    }
+
+    fn next_generation(&self, rules: &[bool; 32]) -> Self {
+        let mut window = 0;
+        let mut plants = Vec::with_capacity(self.plants.len() + 4);
+
+        for plant in self.plants.iter().chain([false; 4].iter()) {
+            window = (window << 1 | *plant as usize) & 0b11111;
+            plants.push(rules[window]);
+        }
+
+        Self {
+            plants,
+            offset: self.offset - 2,
+        }
+        .trimmed()
+    }
+
+    fn sum(&self) -> i64 {
+        self.plants
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| **p)
+            .map(|(i, _)| i as i64 + self.offset)
+            .sum()
+    }
+}
+
+fn parse(input: &str) -> (Pots, [bool; 32]) {
+    let (initial, notes) = input.split_once("\n\n").unwrap();
+    let pots = Pots::from(initial.trim_start_matches("initial state: "));
+
+    let mut rules = [false; 32];
+    for note in notes.lines() {
+        let (pattern, result) = note.split_once(" => ").unwrap();
+        let index = pattern
+            .chars()
+            .fold(0, |acc, c| acc << 1 | (c == '#') as usize);
+        rules[index] = result == "#";
+    }
+
+    (pots, rules)
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,35 +94,78 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> i64 {
+    plants_sum(input, 20)
+}
+
+fn solve_second_part(input: &str) -> i64 {
+    plants_sum(input, 50_000_000_000)
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn plants_sum(input: &str, generations: usize) -> i64 {
+    let (pots, rules) = parse(input);
+
+    extrapolate_steady_state(
+        pots,
+        generations,
+        |pots| pots.next_generation(&rules),
+        |pots| pots.plants.clone(),
+        Pots::sum,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 325;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let (mut pots, rules) = parse(INPUT);
+        for _ in 0..1000 {
+            pots = pots.next_generation(&rules);
+        }
+
+        assert_eq!(pots.sum(), plants_sum(INPUT, 1000))
+    }
+
+    #[test]
+    fn test_generations() {
+        let (mut pots, rules) = parse(INPUT);
+
+        pots = pots.next_generation(&rules);
+        assert_eq!(pots.to_string(), "#...#....#.....#..#..#..#");
+        assert_eq!(pots.offset, 0);
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        for _ in 1..20 {
+            pots = pots.next_generation(&rules);
+        }
+        assert_eq!(pots.to_string(), "#....##....#####...#######....#.#..##");
+        assert_eq!(pots.offset, -2);
     }
 
     // check_answers!(42, 42);
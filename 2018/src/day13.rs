@@ -1,5 +1,211 @@
 use std::fmt::Display;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Point {
+    y: usize,
+    x: usize,
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Turn {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Turn {
+    fn next(&self) -> Self {
+        match self {
+            Turn::Left => Turn::Straight,
+            Turn::Straight => Turn::Right,
+            Turn::Right => Turn::Left,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cart {
+    position: Point,
+    direction: Direction,
+    next_turn: Turn,
+    crashed: bool,
+}
+
+impl Cart {
+    fn advance(&mut self, tracks: &[Vec<char>]) {
+        let Point { y, x } = self.position;
+        self.position = match self.direction {
+            Direction::Up => Point { y: y - 1, x },
+            Direction::Down => Point { y: y + 1, x },
+            Direction::Left => Point { y, x: x - 1 },
+            Direction::Right => Point { y, x: x + 1 },
+        };
+
+        self.direction = match (tracks[self.position.y][self.position.x], self.direction) {
+            ('/', Direction::Up | Direction::Down) => self.direction.turn_right(),
+            ('/', Direction::Left | Direction::Right) => self.direction.turn_left(),
+            ('\\', Direction::Up | Direction::Down) => self.direction.turn_left(),
+            ('\\', Direction::Left | Direction::Right) => self.direction.turn_right(),
+            ('+', _) => {
+                let direction = match self.next_turn {
+                    Turn::Left => self.direction.turn_left(),
+                    Turn::Straight => self.direction,
+                    Turn::Right => self.direction.turn_right(),
+                };
+                self.next_turn = self.next_turn.next();
+                direction
+            }
+            (' ', _) => panic!("Cart left the tracks at {}", self.position),
+            _ => self.direction,
+        };
+    }
+}
+
+#[derive(Debug)]
+struct Mine {
+    tracks: Vec<Vec<char>>,
+    carts: Vec<Cart>,
+    crashes: Vec<Point>,
+}
+
+impl From<&str> for Mine {
+    fn from(value: &str) -> Self {
+        let width = value.lines().map(|l| l.len()).max().unwrap_or_default();
+        let mut tracks = Vec::new();
+        let mut carts = Vec::new();
+
+        for (y, line) in value.lines().enumerate() {
+            let mut row = vec![' '; width];
+
+            for (x, char) in line.chars().enumerate() {
+                let direction = match char {
+                    '^' => Direction::Up,
+                    'v' => Direction::Down,
+                    '<' => Direction::Left,
+                    '>' => Direction::Right,
+                    _ => {
+                        row[x] = char;
+                        continue;
+                    }
+                };
+
+                row[x] = match direction {
+                    Direction::Up | Direction::Down => '|',
+                    Direction::Left | Direction::Right => '-',
+                };
+                carts.push(Cart {
+                    position: Point { y, x },
+                    direction,
+                    next_turn: Turn::Left,
+                    crashed: false,
+                });
+            }
+
+            tracks.push(row);
+        }
+
+        Self {
+            tracks,
+            carts,
+            crashes: Vec::new(),
+        }
+    }
+}
+
+impl Display for Mine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut canvas = self.tracks.clone();
+
+        for cart in &self.carts {
+            canvas[cart.position.y][cart.position.x] = cart.direction.symbol();
+        }
+        for crash in &self.crashes {
+            canvas[crash.y][crash.x] = 'X';
+        }
+
+        for row in canvas {
+            writeln!(f, "{}", row.into_iter().collect::<String>().trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Mine {
+    fn tick(&mut self) {
+        self.crashes.clear();
+        self.carts.sort_by_key(|c| c.position);
+
+        for index in 0..self.carts.len() {
+            if self.carts[index].crashed {
+                continue;
+            }
+
+            self.carts[index].advance(&self.tracks);
+
+            let position = self.carts[index].position;
+            let carts_here = self
+                .carts
+                .iter()
+                .filter(|c| !c.crashed && c.position == position)
+                .count();
+
+            if carts_here > 1 {
+                self.carts
+                    .iter_mut()
+                    .filter(|c| c.position == position)
+                    .for_each(|c| c.crashed = true);
+                self.crashes.push(position);
+            }
+        }
+
+        self.carts.retain(|c| !c.crashed);
+    }
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,35 +213,115 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    let mut mine = Mine::from(input);
+
+    while mine.crashes.is_empty() {
+        mine.tick();
+    }
+
+    mine.crashes[0].to_string()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> String {
+    let mut mine = Mine::from(input);
+
+    while mine.carts.len() > 1 {
+        mine.tick();
+    }
+
+    mine.carts
+        .first()
+        .map(|c| c.position.to_string())
+        .expect("No carts left")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = "7,3";
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let input = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+";
+        let answer = "6,4";
+
+        assert_eq!(answer, solve_second_part(input))
+    }
+
+    #[test]
+    fn test_ticks() {
+        let mut mine = Mine::from(INPUT);
+        assert_eq!(mine.to_string(), INPUT);
+
+        mine.tick();
+        assert_eq!(
+            mine.to_string(),
+            r"/-->\
+|   |  /----\
+| /-+--+-\  |
+| | |  | |  |
+\-+-/  \->--/
+  \------/
+"
+        );
+
+        mine.tick();
+        assert_eq!(
+            mine.to_string(),
+            r"/---v
+|   |  /----\
+| /-+--+-\  |
+| | |  | |  |
+\-+-/  \-+>-/
+  \------/
+"
+        );
+
+        (0..11).for_each(|_| mine.tick());
+        assert_eq!(
+            mine.to_string(),
+            r"/---\
+|   |  /----\
+| /-+--v-\  |
+| | |  | |  |
+\-+-/  ^-+--/
+  \------/
+"
+        );
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        mine.tick();
+        assert_eq!(
+            mine.to_string(),
+            r"/---\
+|   |  /----\
+| /-+--+-\  |
+| | |  X |  |
+\-+-/  \-+--/
+  \------/
+"
+        );
     }
 
     // check_answers!(42, 42);
@@ -1,5 +1,56 @@
 use std::fmt::Display;
 
+#[derive(Debug)]
+struct Scoreboard {
+    scores: Vec<u8>,
+    elves: [usize; 2],
+}
+
+impl Scoreboard {
+    fn new() -> Self {
+        Self {
+            scores: vec![3, 7],
+            elves: [0, 1],
+        }
+    }
+
+    fn step(&mut self) {
+        let sum = self.elves.iter().map(|e| self.scores[*e]).sum::<u8>();
+        if sum >= 10 {
+            self.scores.push(sum / 10);
+        }
+        self.scores.push(sum % 10);
+
+        for elf in self.elves.iter_mut() {
+            *elf = (*elf + 1 + self.scores[*elf] as usize) % self.scores.len();
+        }
+    }
+
+    fn ten_after(&mut self, recipes: usize) -> String {
+        while self.scores.len() < recipes + 10 {
+            self.step();
+        }
+
+        self.scores[recipes..recipes + 10]
+            .iter()
+            .map(|s| (b'0' + s) as char)
+            .collect()
+    }
+
+    fn find(&mut self, pattern: &[u8]) -> usize {
+        loop {
+            let len = self.scores.len();
+            for end in [len - 1, len] {
+                if end >= pattern.len() && self.scores[end - pattern.len()..end] == *pattern {
+                    return end - pattern.len();
+                }
+            }
+
+            self.step();
+        }
+    }
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,35 +58,48 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> String {
+    Scoreboard::new().ten_after(input.trim().parse().unwrap())
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    let pattern = input.trim().bytes().map(|b| b - b'0').collect::<Vec<_>>();
+
+    Scoreboard::new().find(&pattern)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "2018
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = "5941429882";
 
-        assert_eq!(answer, solve_first_part(INPUT))
+        assert_eq!(answer, solve_first_part(INPUT));
+        assert_eq!("5158916779", solve_first_part("9"));
+        assert_eq!("0124515891", solve_first_part("5"));
+        assert_eq!("9251071085", solve_first_part("18"));
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 2018;
+
+        assert_eq!(answer, solve_second_part("59414"));
+        assert_eq!(9, solve_second_part("51589"));
+        assert_eq!(5, solve_second_part("01245"));
+        assert_eq!(18, solve_second_part("92510"));
+    }
 
-        assert_eq!(answer, solve_second_part(INPUT))
+    #[test]
+    fn test_pattern_in_second_to_last_position() {
+        assert_eq!(Scoreboard::new().find(&[3, 7, 1]), 0);
+        assert_eq!(Scoreboard::new().find(&[7, 1, 0]), 1);
+        assert_eq!(Scoreboard::new().find(&[3, 7]), 0);
     }
 
     // check_answers!(42, 42);
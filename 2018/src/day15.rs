@@ -1,4 +1,277 @@
-use std::fmt::Display;
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Point {
+    y: usize,
+    x: usize,
+}
+
+impl Point {
+    fn neighbours(&self) -> [Point; 4] {
+        [
+            Point {
+                y: self.y - 1,
+                x: self.x,
+            },
+            Point {
+                y: self.y,
+                x: self.x - 1,
+            },
+            Point {
+                y: self.y,
+                x: self.x + 1,
+            },
+            Point {
+                y: self.y + 1,
+                x: self.x,
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Race {
+    Elf,
+    Goblin,
+}
+
+impl Display for Race {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Race::Elf => 'E',
+            Race::Goblin => 'G',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Unit {
+    race: Race,
+    position: Point,
+    hit_points: i32,
+    attack_power: i32,
+}
+
+impl Unit {
+    fn is_alive(&self) -> bool {
+        self.hit_points > 0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Battle {
+    walls: Vec<Vec<bool>>,
+    units: Vec<Unit>,
+    rounds: usize,
+    elf_died: bool,
+}
+
+impl From<&str> for Battle {
+    fn from(value: &str) -> Self {
+        let mut walls = Vec::new();
+        let mut units = Vec::new();
+
+        for (y, line) in value.lines().enumerate() {
+            let mut row = Vec::new();
+
+            for (x, char) in line.chars().enumerate() {
+                row.push(char == '#');
+
+                let race = match char {
+                    'E' => Race::Elf,
+                    'G' => Race::Goblin,
+                    _ => continue,
+                };
+                units.push(Unit {
+                    race,
+                    position: Point { y, x },
+                    hit_points: 200,
+                    attack_power: 3,
+                });
+            }
+
+            walls.push(row);
+        }
+
+        Self {
+            walls,
+            units,
+            rounds: 0,
+            elf_died: false,
+        }
+    }
+}
+
+impl Display for Battle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.walls.iter().enumerate() {
+            let mut units = Vec::new();
+
+            for (x, wall) in row.iter().enumerate() {
+                let unit = self.unit_at(Point { y, x });
+
+                if let Some(unit) = unit {
+                    write!(f, "{}", unit.race)?;
+                    units.push(format!("{}({})", unit.race, unit.hit_points));
+                } else if *wall {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+
+            if !units.is_empty() {
+                write!(f, "   {}", units.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Battle {
+    fn with_elf_attack_power(mut self, attack_power: i32) -> Self {
+        for unit in self.units.iter_mut().filter(|u| u.race == Race::Elf) {
+            unit.attack_power = attack_power;
+        }
+        self
+    }
+
+    fn fight(&mut self, stop_on_elf_death: bool) -> Option<usize> {
+        while self.round(stop_on_elf_death) {}
+
+        if stop_on_elf_death && self.elf_died {
+            return None;
+        }
+
+        Some(self.outcome())
+    }
+
+    fn outcome(&self) -> usize {
+        let hit_points = self
+            .units
+            .iter()
+            .filter(|u| u.is_alive())
+            .map(|u| u.hit_points as usize)
+            .sum::<usize>();
+
+        self.rounds * hit_points
+    }
+
+    fn round(&mut self, stop_on_elf_death: bool) -> bool {
+        self.units.sort_by_key(|u| u.position);
+
+        for index in 0..self.units.len() {
+            if !self.units[index].is_alive() {
+                continue;
+            }
+
+            let race = self.units[index].race;
+            if !self.units.iter().any(|u| u.is_alive() && u.race != race) {
+                self.units.retain(|u| u.is_alive());
+                return false;
+            }
+
+            if self.find_target(index).is_none() {
+                if let Some(step) = self.find_step(index) {
+                    self.units[index].position = step;
+                }
+            }
+
+            if let Some(target) = self.find_target(index) {
+                let attack_power = self.units[index].attack_power;
+                let target = &mut self.units[target];
+
+                target.hit_points -= attack_power;
+                if !target.is_alive() && target.race == Race::Elf {
+                    self.elf_died = true;
+                    if stop_on_elf_death {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        self.units.retain(|u| u.is_alive());
+        self.rounds += 1;
+
+        true
+    }
+
+    fn find_target(&self, index: usize) -> Option<usize> {
+        let unit = &self.units[index];
+        let neighbours = unit.position.neighbours();
+
+        self.units
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| u.is_alive() && u.race != unit.race)
+            .filter(|(_, u)| neighbours.contains(&u.position))
+            .min_by_key(|(_, u)| (u.hit_points, u.position))
+            .map(|(i, _)| i)
+    }
+
+    fn find_step(&self, index: usize) -> Option<Point> {
+        let unit = &self.units[index];
+
+        let in_range = self
+            .units
+            .iter()
+            .filter(|u| u.is_alive() && u.race != unit.race)
+            .flat_map(|u| u.position.neighbours())
+            .filter(|p| self.is_open(*p))
+            .collect::<HashSet<_>>();
+
+        let distances = self.distances(unit.position);
+        let destination = in_range
+            .into_iter()
+            .filter_map(|p| distances[p.y][p.x].map(|d| (d, p)))
+            .min()?
+            .1;
+
+        let distances = self.distances(destination);
+        unit.position
+            .neighbours()
+            .into_iter()
+            .filter_map(|p| distances[p.y][p.x].map(|d| (d, p)))
+            .min()
+            .map(|(_, p)| p)
+    }
+
+    fn distances(&self, from: Point) -> Vec<Vec<Option<usize>>> {
+        let mut distances = vec![vec![None; self.walls[0].len()]; self.walls.len()];
+        let mut queue = VecDeque::new();
+
+        distances[from.y][from.x] = Some(0);
+        queue.push_back((from, 0));
+
+        while let Some((point, distance)) = queue.pop_front() {
+            for next in point.neighbours() {
+                if distances[next.y][next.x].is_none() && self.is_open(next) {
+                    distances[next.y][next.x] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    fn is_open(&self, point: Point) -> bool {
+        !self.walls[point.y][point.x] && self.unit_at(point).is_none()
+    }
+
+    fn unit_at(&self, point: Point) -> Option<&Unit> {
+        self.units
+            .iter()
+            .find(|u| u.is_alive() && u.position == point)
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +280,168 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Battle::from(input).fight(false).unwrap()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    let battle = Battle::from(input);
+
+    (4..)
+        .find_map(|attack_power| {
+            battle
+                .clone()
+                .with_elf_attack_power(attack_power)
+                .fight(true)
+        })
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
 ";
 
-    #[ignore]
+    const EXAMPLES: [&str; 5] = [
+        "#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######
+",
+        "#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######
+",
+        "#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######
+",
+        "#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######
+",
+        "#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+",
+    ];
+
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 27730;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 4988;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_examples() {
+        assert_eq!(solve_first_part(EXAMPLES[0]), 36334);
+        assert_eq!(solve_first_part(EXAMPLES[1]), 39514);
+        assert_eq!(solve_first_part(EXAMPLES[2]), 27755);
+        assert_eq!(solve_first_part(EXAMPLES[3]), 28944);
+        assert_eq!(solve_first_part(EXAMPLES[4]), 18740);
+
+        assert_eq!(solve_second_part(EXAMPLES[1]), 31284);
+        assert_eq!(solve_second_part(EXAMPLES[2]), 3478);
+        assert_eq!(solve_second_part(EXAMPLES[3]), 6474);
+        assert_eq!(solve_second_part(EXAMPLES[4]), 1140);
+    }
+
+    #[test]
+    fn test_stop_on_elf_death() {
+        let mut battle = Battle::from(INPUT);
+
+        // The first elf falls during round 23, which is abandoned unfinished.
+        assert_eq!(battle.fight(true), None);
+        assert!(battle.elf_died);
+        assert_eq!(battle.rounds, 22);
+
+        assert_eq!(
+            Battle::from(INPUT).with_elf_attack_power(15).fight(true),
+            Some(4988)
+        );
+    }
+
+    #[test]
+    fn test_battle_rendering() {
+        let mut battle = Battle::from(INPUT);
+
+        battle.round(false);
+        assert_eq!(
+            battle.to_string(),
+            "#######
+#..G..#   G(200)
+#...EG#   E(197), G(197)
+#.#G#G#   G(200), G(197)
+#...#E#   E(197)
+#.....#
+#######
+"
+        );
+
+        battle.round(false);
+        assert_eq!(
+            battle.to_string(),
+            "#######
+#...G.#   G(200)
+#..GEG#   G(200), E(188), G(194)
+#.#.#G#   G(194)
+#...#E#   E(194)
+#.....#
+#######
+"
+        );
+
+        while battle.round(false) {}
+        assert_eq!(battle.rounds, 47);
+        assert_eq!(
+            battle.to_string(),
+            "#######
+#G....#   G(200)
+#.G...#   G(131)
+#.#.#G#   G(59)
+#...#.#
+#....G#   G(200)
+#######
+"
+        );
+    }
+
     // check_answers!(42, 42);
 }
@@ -1,4 +1,176 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::RangeInclusive};
+
+const SPRING_X: usize = 500;
+
+#[derive(Debug)]
+struct Vein {
+    xs: RangeInclusive<usize>,
+    ys: RangeInclusive<usize>,
+}
+
+impl From<&str> for Vein {
+    fn from(value: &str) -> Self {
+        let (first, second) = value.split_once(", ").unwrap();
+        let (first_axis, first_value) = first.split_once('=').unwrap();
+        let (_, second_value) = second.split_once('=').unwrap();
+
+        let first_value = first_value.parse().unwrap();
+        let first = first_value..=first_value;
+        let (start, end) = second_value.split_once("..").unwrap();
+        let second = start.parse().unwrap()..=end.parse().unwrap();
+
+        match first_axis {
+            "x" => Self {
+                xs: first,
+                ys: second,
+            },
+            "y" => Self {
+                xs: second,
+                ys: first,
+            },
+            _ => panic!("Unexpected axis: {first_axis}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Sand,
+    Clay,
+    Flowing,
+    Settled,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Cell::Sand => '.',
+            Cell::Clay => '#',
+            Cell::Flowing => '|',
+            Cell::Settled => '~',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Ground {
+    cells: Vec<Vec<Cell>>,
+    min_x: usize,
+    min_y: usize,
+    max_y: usize,
+}
+
+impl From<&str> for Ground {
+    fn from(value: &str) -> Self {
+        let veins = value.lines().map(Vein::from).collect::<Vec<_>>();
+
+        let min_x = veins.iter().map(|v| *v.xs.start()).min().unwrap() - 1;
+        let max_x = veins.iter().map(|v| *v.xs.end()).max().unwrap() + 1;
+        let min_y = veins.iter().map(|v| *v.ys.start()).min().unwrap();
+        let max_y = veins.iter().map(|v| *v.ys.end()).max().unwrap();
+
+        let mut cells = vec![vec![Cell::Sand; max_x - min_x + 1]; max_y + 2];
+        for vein in veins {
+            for y in vein.ys.clone() {
+                for x in vein.xs.clone() {
+                    cells[y][x - min_x] = Cell::Clay;
+                }
+            }
+        }
+
+        Self {
+            cells,
+            min_x,
+            min_y,
+            max_y,
+        }
+    }
+}
+
+impl Display for Ground {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.cells.iter().take(self.max_y + 1).enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if y == 0 && x + self.min_x == SPRING_X {
+                    write!(f, "+")?;
+                } else {
+                    write!(f, "{cell}")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Ground {
+    fn flood(&mut self) {
+        self.fill(SPRING_X - self.min_x, 1, Direction::Down);
+    }
+
+    fn fill(&mut self, x: usize, y: usize, direction: Direction) -> bool {
+        if y > self.max_y {
+            return false;
+        }
+
+        match self.cells[y][x] {
+            Cell::Clay => return true,
+            Cell::Flowing => return false,
+            _ => {}
+        }
+
+        self.cells[y][x] = Cell::Flowing;
+
+        if self.cells[y + 1][x] == Cell::Sand {
+            self.fill(x, y + 1, Direction::Down);
+        }
+        if !matches!(self.cells[y + 1][x], Cell::Clay | Cell::Settled) {
+            return false;
+        }
+
+        match direction {
+            Direction::Left => self.fill(x - 1, y, Direction::Left),
+            Direction::Right => self.fill(x + 1, y, Direction::Right),
+            Direction::Down => {
+                let left = self.fill(x - 1, y, Direction::Left);
+                let right = self.fill(x + 1, y, Direction::Right);
+
+                if left && right {
+                    self.settle(x, y);
+                }
+
+                left && right
+            }
+        }
+    }
+
+    fn settle(&mut self, x: usize, y: usize) {
+        let (left, right) = self.cells[y].split_at_mut(x);
+
+        left.iter_mut()
+            .rev()
+            .take_while(|c| **c != Cell::Clay)
+            .chain(right.iter_mut().take_while(|c| **c != Cell::Clay))
+            .for_each(|c| *c = Cell::Settled);
+    }
+
+    fn count(&self, predicate: impl Fn(&Cell) -> bool) -> usize {
+        self.cells[self.min_y..=self.max_y]
+            .iter()
+            .flatten()
+            .filter(|c| predicate(c))
+            .count()
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +179,105 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    let mut ground = Ground::from(input);
+    ground.flood();
+
+    ground.count(|c| matches!(c, Cell::Flowing | Cell::Settled))
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    let mut ground = Ground::from(input);
+    ground.flood();
+
+    ground.count(|c| *c == Cell::Settled)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "x=495, y=2..7
+y=7, x=495..501
+x=501, y=3..7
+x=498, y=2..4
+x=506, y=1..2
+x=498, y=10..13
+x=504, y=10..13
+y=13, x=498..504
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 57;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 29;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_rendering() {
+        let mut ground = Ground::from(INPUT);
+        assert_eq!(
+            ground.to_string(),
+            "......+.......
+............#.
+.#..#.......#.
+.#..#..#......
+.#..#..#......
+.#.....#......
+.#.....#......
+.#######......
+..............
+..............
+....#.....#...
+....#.....#...
+....#.....#...
+....#######...
+"
+        );
+
+        ground.flood();
+        assert_eq!(
+            ground.to_string(),
+            "......+.......
+......|.....#.
+.#..#||||...#.
+.#..#~~#|.....
+.#..#~~#|.....
+.#~~~~~#|.....
+.#~~~~~#|.....
+.#######|.....
+........|.....
+...|||||||||..
+...|#~~~~~#|..
+...|#~~~~~#|..
+...|#~~~~~#|..
+...|#######|..
+"
+        );
+    }
+
+    #[test]
+    fn test_nested_basin() {
+        let input = "x=495, y=3..10
+x=505, y=3..10
+y=10, x=495..505
+x=499, y=6..7
+x=501, y=6..7
+y=7, x=499..501
+";
+        let mut ground = Ground::from(input);
+        ground.flood();
+
+        assert_eq!(ground.count(|c| *c == Cell::Settled), 7 * 9 - 5);
+    }
+
     // check_answers!(42, 42);
 }
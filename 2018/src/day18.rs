@@ -1,4 +1,124 @@
-use std::fmt::Display;
+use std::{fmt::Display, mem};
+
+use crate::shared::cycle::Cycle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Acre {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+impl From<char> for Acre {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Open,
+            '|' => Self::Trees,
+            '#' => Self::Lumberyard,
+            _ => panic!("Unexpected acre: {value}"),
+        }
+    }
+}
+
+impl Display for Acre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Acre::Open => '.',
+            Acre::Trees => '|',
+            Acre::Lumberyard => '#',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Area {
+    acres: Vec<Vec<Acre>>,
+    buffer: Vec<Vec<Acre>>,
+}
+
+impl PartialEq for Area {
+    fn eq(&self, other: &Self) -> bool {
+        self.acres == other.acres
+    }
+}
+
+impl From<&str> for Area {
+    fn from(value: &str) -> Self {
+        let acres = value
+            .lines()
+            .map(|line| line.chars().map(Acre::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let buffer = acres.clone();
+
+        Self { acres, buffer }
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.acres {
+            for acre in row {
+                write!(f, "{acre}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Area {
+    fn tick(&mut self) {
+        for (y, row) in self.acres.iter().enumerate() {
+            for (x, acre) in row.iter().enumerate() {
+                let (trees, lumberyards) = self.count_neighbours(x, y);
+
+                self.buffer[y][x] = match acre {
+                    Acre::Open if trees >= 3 => Acre::Trees,
+                    Acre::Trees if lumberyards >= 3 => Acre::Lumberyard,
+                    Acre::Lumberyard if lumberyards == 0 || trees == 0 => Acre::Open,
+                    _ => *acre,
+                };
+            }
+        }
+
+        mem::swap(&mut self.acres, &mut self.buffer);
+    }
+
+    fn count_neighbours(&self, x: usize, y: usize) -> (usize, usize) {
+        let mut trees = 0;
+        let mut lumberyards = 0;
+
+        for ny in y.saturating_sub(1)..=(y + 1).min(self.acres.len() - 1) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(self.acres[ny].len() - 1) {
+                if (nx, ny) == (x, y) {
+                    continue;
+                }
+
+                match self.acres[ny][nx] {
+                    Acre::Trees => trees += 1,
+                    Acre::Lumberyard => lumberyards += 1,
+                    Acre::Open => {}
+                }
+            }
+        }
+
+        (trees, lumberyards)
+    }
+
+    fn resource_value(&self) -> usize {
+        let acres = self.acres.iter().flatten();
+        let trees = acres.clone().filter(|a| **a == Acre::Trees).count();
+        let lumberyards = acres.filter(|a| **a == Acre::Lumberyard).count();
+
+        trees * lumberyards
+    }
+
+    fn after(&self, minutes: usize) -> Self {
+        Cycle::find(self, Area::tick).state_at(self, Area::tick, minutes)
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +127,90 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    let mut area = Area::from(input);
+
+    for _ in 0..10 {
+        area.tick();
+    }
+
+    area.resource_value()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    Area::from(input).after(1_000_000_000).resource_value()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = ".#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 1147;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 0;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_evolution() {
+        let mut area = Area::from(INPUT);
+
+        area.tick();
+        assert_eq!(
+            area.to_string(),
+            ".......##.
+......|###
+.|..|...#.
+..|#||...#
+..##||.|#|
+...#||||..
+||...|||..
+|||||.||.|
+||||||||||
+....||..|.
+"
+        );
+
+        for _ in 1..10 {
+            area.tick();
+        }
+        assert_eq!(
+            area.to_string(),
+            ".||##.....
+||###.....
+||##......
+|##.....##
+|##.....##
+|##....##|
+||##.####|
+||#####|||
+||||#|||||
+||||||||||
+"
+        );
+
+        assert_eq!(area, Area::from(INPUT).after(10));
+    }
+
     // check_answers!(42, 42);
 }
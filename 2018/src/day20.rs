@@ -1,4 +1,130 @@
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn step(&self, direction: char) -> Self {
+        let Point { x, y } = *self;
+        match direction {
+            'N' => Point { x, y: y - 1 },
+            'S' => Point { x, y: y + 1 },
+            'W' => Point { x: x - 1, y },
+            'E' => Point { x: x + 1, y },
+            _ => panic!("Unexpected direction: {direction}"),
+        }
+    }
+}
+
+const ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[derive(Debug)]
+struct Map {
+    doors: HashMap<Point, HashSet<Point>>,
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        let mut map = Self {
+            doors: HashMap::new(),
+        };
+        let mut current = HashSet::from([ORIGIN]);
+        let mut branches: Vec<(HashSet<Point>, HashSet<Point>)> = Vec::new();
+
+        for char in value.trim().chars() {
+            match char {
+                '^' | '$' => {}
+                'N' | 'S' | 'W' | 'E' => {
+                    current = current
+                        .into_iter()
+                        .map(|room| {
+                            let next = room.step(char);
+                            map.connect(room, next);
+                            next
+                        })
+                        .collect();
+                }
+                '(' => branches.push((current.clone(), HashSet::new())),
+                '|' => {
+                    let (starts, ends) = branches.last_mut().expect("Unexpected '|'");
+                    ends.extend(current);
+                    current = starts.clone();
+                }
+                ')' => {
+                    let (_, mut ends) = branches.pop().expect("Unexpected ')'");
+                    ends.extend(current);
+                    current = ends;
+                }
+                _ => panic!("Unexpected character: {char}"),
+            }
+        }
+
+        map
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rooms = self.doors.keys().chain([&ORIGIN]);
+        let min_x = rooms.clone().map(|r| r.x).min().unwrap();
+        let max_x = rooms.clone().map(|r| r.x).max().unwrap();
+        let min_y = rooms.clone().map(|r| r.y).min().unwrap();
+        let max_y = rooms.map(|r| r.y).max().unwrap();
+
+        let width = ((max_x - min_x) * 2 + 3) as usize;
+        let height = ((max_y - min_y) * 2 + 3) as usize;
+        let mut canvas = vec![vec!['#'; width]; height];
+
+        for (room, neighbours) in &self.doors {
+            let x = ((room.x - min_x) * 2 + 1) as usize;
+            let y = ((room.y - min_y) * 2 + 1) as usize;
+            canvas[y][x] = '.';
+
+            for neighbour in neighbours {
+                let door_x = (x as i32 + neighbour.x - room.x) as usize;
+                let door_y = (y as i32 + neighbour.y - room.y) as usize;
+                canvas[door_y][door_x] = if neighbour.x == room.x { '-' } else { '|' };
+            }
+        }
+
+        canvas[((ORIGIN.y - min_y) * 2 + 1) as usize][((ORIGIN.x - min_x) * 2 + 1) as usize] = 'X';
+
+        for row in canvas {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Map {
+    fn connect(&mut self, from: Point, to: Point) {
+        self.doors.entry(from).or_default().insert(to);
+        self.doors.entry(to).or_default().insert(from);
+    }
+
+    fn distances(&self) -> HashMap<Point, usize> {
+        let mut distances = HashMap::from([(ORIGIN, 0)]);
+        let mut queue = VecDeque::from([(ORIGIN, 0)]);
+
+        while let Some((room, distance)) = queue.pop_front() {
+            for neighbour in self.doors.get(&room).into_iter().flatten() {
+                if !distances.contains_key(neighbour) {
+                    distances.insert(*neighbour, distance + 1);
+                    queue.push_back((*neighbour, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +133,115 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Map::from(input)
+        .distances()
+        .into_values()
+        .max()
+        .unwrap_or_default()
+}
+
+fn solve_second_part(input: &str) -> usize {
+    count_distant_rooms(input, 1000)
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn count_distant_rooms(input: &str, doors: usize) -> usize {
+    Map::from(input)
+        .distances()
+        .into_values()
+        .filter(|d| *d >= doors)
+        .count()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "^ENWWW(NEEE|SSE(EE|N))$
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 10;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 0;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_furthest_room() {
+        assert_eq!(solve_first_part("^WNE$"), 3);
+        assert_eq!(
+            solve_first_part("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$"),
+            18
+        );
+        assert_eq!(
+            solve_first_part("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$"),
+            23
+        );
+        assert_eq!(
+            solve_first_part("^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$"),
+            31
+        );
+    }
+
+    #[test]
+    fn test_distant_rooms() {
+        assert_eq!(count_distant_rooms("^WNE$", 2), 2);
+        assert_eq!(count_distant_rooms(INPUT, 10), 1);
+        assert_eq!(count_distant_rooms(INPUT, 5), 11);
+    }
+
+    #[test]
+    fn test_rendering() {
+        assert_eq!(Map::from("^$").to_string(), "###\n#X#\n###\n");
+
+        assert_eq!(
+            Map::from("^WNE$").to_string(),
+            "#####
+#.|.#
+#-###
+#.|X#
+#####
+"
+        );
+
+        assert_eq!(
+            Map::from(INPUT).to_string(),
+            "#########
+#.|.|.|.#
+#-#######
+#.|.|.|.#
+#-#####-#
+#.#.#X|.#
+#-#-#####
+#.|.|.|.#
+#########
+"
+        );
+
+        assert_eq!(
+            Map::from("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$").to_string(),
+            "###########
+#.|.#.|.#.#
+#-###-#-#-#
+#.|.|.#.#.#
+#-#####-#-#
+#.#.#X|.#.#
+#-#-#####-#
+#.#.|.|.|.#
+#-###-###-#
+#.|.|.#.|.#
+###########
+"
+        );
+    }
+
     // check_answers!(42, 42);
 }
@@ -1,4 +1,166 @@
-use std::fmt::Display;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::Display,
+};
+
+const MOVE_TIME: usize = 1;
+const SWITCH_TIME: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Rocky,
+    Wet,
+    Narrow,
+}
+
+impl Region {
+    fn risk(&self) -> usize {
+        match self {
+            Region::Rocky => 0,
+            Region::Wet => 1,
+            Region::Narrow => 2,
+        }
+    }
+
+    fn allows(&self, tool: Tool) -> bool {
+        !matches!(
+            (self, tool),
+            (Region::Rocky, Tool::Neither)
+                | (Region::Wet, Tool::Torch)
+                | (Region::Narrow, Tool::ClimbingGear)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Tool {
+    Torch,
+    ClimbingGear,
+    Neither,
+}
+
+const TOOLS: [Tool; 3] = [Tool::Torch, Tool::ClimbingGear, Tool::Neither];
+
+#[derive(Debug)]
+struct Cave {
+    depth: usize,
+    target: (usize, usize),
+    erosion: Vec<Vec<usize>>,
+}
+
+impl From<&str> for Cave {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines();
+        let depth = lines.next().unwrap().trim_start_matches("depth: ");
+        let target = lines.next().unwrap().trim_start_matches("target: ");
+        let (x, y) = target.split_once(',').unwrap();
+
+        Self {
+            depth: depth.parse().unwrap(),
+            target: (x.parse().unwrap(), y.parse().unwrap()),
+            erosion: Vec::new(),
+        }
+    }
+}
+
+impl Cave {
+    fn erosion_level(&mut self, x: usize, y: usize) -> usize {
+        self.expand(x, y);
+        self.erosion[y][x]
+    }
+
+    fn region(&mut self, x: usize, y: usize) -> Region {
+        match self.erosion_level(x, y) % 3 {
+            0 => Region::Rocky,
+            1 => Region::Wet,
+            _ => Region::Narrow,
+        }
+    }
+
+    fn expand(&mut self, x: usize, y: usize) {
+        let height = self.erosion.len();
+        let width = self.erosion.first().map_or(0, |row| row.len());
+        if x < width && y < height {
+            return;
+        }
+
+        let new_width = width.max(x + 1);
+        let new_height = height.max(y + 1);
+        self.erosion.resize(new_height, Vec::new());
+
+        for cy in 0..new_height {
+            for cx in self.erosion[cy].len()..new_width {
+                let geologic_index = match (cx, cy) {
+                    (0, 0) => 0,
+                    position if position == self.target => 0,
+                    (cx, 0) => cx * 16807,
+                    (0, cy) => cy * 48271,
+                    (cx, cy) => self.erosion[cy][cx - 1] * self.erosion[cy - 1][cx],
+                };
+
+                let erosion_level = (geologic_index + self.depth) % 20183;
+                self.erosion[cy].push(erosion_level);
+            }
+        }
+    }
+
+    fn risk_level(&mut self) -> usize {
+        let (target_x, target_y) = self.target;
+
+        (0..=target_y)
+            .flat_map(|y| (0..=target_x).map(move |x| (x, y)))
+            .map(|(x, y)| self.region(x, y).risk())
+            .sum()
+    }
+
+    fn rescue_time(&mut self) -> usize {
+        let start = (0, 0, Tool::Torch);
+        let goal = (self.target.0, self.target.1, Tool::Torch);
+
+        let mut times = HashMap::from([(start, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((time, state))) = queue.pop() {
+            if state == goal {
+                return time;
+            }
+            if times.get(&state).is_some_and(|t| *t < time) {
+                continue;
+            }
+
+            let (x, y, tool) = state;
+            let region = self.region(x, y);
+
+            let switches = TOOLS
+                .into_iter()
+                .filter(|t| *t != tool && region.allows(*t))
+                .map(|t| ((x, y, t), time + SWITCH_TIME));
+
+            let neighbours = [
+                (x.checked_sub(1), Some(y)),
+                (Some(x + 1), Some(y)),
+                (Some(x), y.checked_sub(1)),
+                (Some(x), Some(y + 1)),
+            ];
+            let moves = neighbours
+                .into_iter()
+                .filter_map(|(nx, ny)| Some((nx?, ny?)))
+                .filter(|(nx, ny)| self.region(*nx, *ny).allows(tool))
+                .map(|(nx, ny)| ((nx, ny, tool), time + MOVE_TIME))
+                .collect::<Vec<_>>();
+
+            for (next, next_time) in switches.chain(moves) {
+                if times.get(&next).is_none_or(|t| next_time < *t) {
+                    times.insert(next, next_time);
+                    queue.push(Reverse((next_time, next)));
+                }
+            }
+        }
+
+        unreachable!()
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +169,50 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    Cave::from(input).risk_level()
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    Cave::from(input).rescue_time()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "depth: 510
+target: 10,10
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 114;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 45;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_regions() {
+        let mut cave = Cave::from(INPUT);
+
+        assert_eq!(cave.erosion_level(10, 10), 510);
+        assert_eq!(cave.region(10, 10), Region::Rocky);
+        assert_eq!(cave.erosion_level(1, 1), 1805);
+        assert_eq!(cave.region(1, 1), Region::Narrow);
+        assert_eq!(cave.erosion_level(0, 0), 510);
+        assert_eq!(cave.erosion_level(1, 0), 17317);
+        assert_eq!(cave.region(1, 0), Region::Wet);
+        assert_eq!(cave.erosion_level(0, 1), 8415);
+        assert_eq!(cave.region(0, 1), Region::Rocky);
+    }
+
     // check_answers!(42, 42);
 }
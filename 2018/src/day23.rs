@@ -1,4 +1,83 @@
-use std::fmt::Display;
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point {
+    fn distance(&self, other: &Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+const ORIGIN: Point = Point { x: 0, y: 0, z: 0 };
+
+#[derive(Debug)]
+struct Nanobot {
+    position: Point,
+    radius: i64,
+}
+
+impl From<&str> for Nanobot {
+    fn from(value: &str) -> Self {
+        let (position, radius) = value.split_once(">, r=").unwrap();
+        let mut coordinates = position
+            .trim_start_matches("pos=<")
+            .split(',')
+            .map(|c| c.parse().unwrap());
+
+        Self {
+            position: Point {
+                x: coordinates.next().unwrap(),
+                y: coordinates.next().unwrap(),
+                z: coordinates.next().unwrap(),
+            },
+            radius: radius.parse().unwrap(),
+        }
+    }
+}
+
+impl Nanobot {
+    fn in_range(&self, point: &Point) -> bool {
+        self.position.distance(point) <= self.radius
+    }
+
+    fn reaches(&self, cube: &Cube) -> bool {
+        cube.distance(&self.position) <= self.radius
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Cube {
+    corner: Point,
+    size: i64,
+}
+
+impl Cube {
+    fn distance(&self, point: &Point) -> i64 {
+        let axis = |from: i64, to: i64, p: i64| (from - p).max(0) + (p - to).max(0);
+
+        axis(self.corner.x, self.corner.x + self.size - 1, point.x)
+            + axis(self.corner.y, self.corner.y + self.size - 1, point.y)
+            + axis(self.corner.z, self.corner.z + self.size - 1, point.z)
+    }
+
+    fn split(&self) -> impl Iterator<Item = Cube> + '_ {
+        let size = self.size / 2;
+
+        (0..8).map(move |i| Cube {
+            corner: Point {
+                x: self.corner.x + (i & 1) * size,
+                y: self.corner.y + (i >> 1 & 1) * size,
+                z: self.corner.z + (i >> 2 & 1) * size,
+            },
+            size,
+        })
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,35 +86,150 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    let nanobots = parse(input);
+    let strongest = nanobots.iter().max_by_key(|n| n.radius).unwrap();
+
+    nanobots
+        .iter()
+        .filter(|n| strongest.in_range(&n.position))
+        .count()
+}
+
+fn solve_second_part(input: &str) -> i64 {
+    best_position(&parse(input)).distance(&ORIGIN)
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn parse(input: &str) -> Vec<Nanobot> {
+    input.lines().map(Nanobot::from).collect()
+}
+
+fn best_position(nanobots: &[Nanobot]) -> Point {
+    let min = nanobots
+        .iter()
+        .flat_map(|n| [n.position.x, n.position.y, n.position.z].map(|c| c - n.radius))
+        .min()
+        .unwrap();
+    let max = nanobots
+        .iter()
+        .flat_map(|n| [n.position.x, n.position.y, n.position.z].map(|c| c + n.radius))
+        .max()
+        .unwrap();
+
+    let mut size = 1;
+    while min + size <= max {
+        size *= 2;
+    }
+
+    let cube = Cube {
+        corner: Point {
+            x: min,
+            y: min,
+            z: min,
+        },
+        size,
+    };
+    let score = |cube: Cube| {
+        let count = nanobots.iter().filter(|n| n.reaches(&cube)).count();
+        (
+            count,
+            Reverse(cube.distance(&ORIGIN)),
+            Reverse(cube.size),
+            cube,
+        )
+    };
+
+    let mut queue = BinaryHeap::from([score(cube)]);
+
+    while let Some((_, _, _, cube)) = queue.pop() {
+        if cube.size == 1 {
+            return cube.corner;
+        }
+
+        queue.extend(cube.split().map(score));
+    }
+
+    unreachable!()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::random::XorShift;
 
-    const INPUT: &str = "
+    const INPUT: &str = "pos=<0,0,0>, r=4
+pos=<1,0,0>, r=1
+pos=<4,0,0>, r=3
+pos=<0,2,0>, r=1
+pos=<0,5,0>, r=3
+pos=<0,0,3>, r=1
+pos=<1,1,1>, r=1
+pos=<1,1,2>, r=1
+pos=<1,3,1>, r=1
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 7;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let input = "pos=<10,12,12>, r=2
+pos=<12,14,12>, r=2
+pos=<16,12,12>, r=4
+pos=<14,14,14>, r=6
+pos=<50,50,50>, r=200
+pos=<10,10,10>, r=5
+";
+        let answer = 36;
+
+        assert_eq!(answer, solve_second_part(input));
+        assert_eq!(
+            best_position(&parse(input)),
+            Point {
+                x: 12,
+                y: 12,
+                z: 12
+            }
+        );
+    }
+
+    #[test]
+    fn test_best_position_brute_force() {
+        let mut rng = XorShift::new(0x2018);
+        let mut random = |range: i64| (rng.next_u64() % (2 * range as u64 + 1)) as i64 - range;
+
+        for _ in 0..20 {
+            let nanobots = (0..8)
+                .map(|_| Nanobot {
+                    position: Point {
+                        x: random(10),
+                        y: random(10),
+                        z: random(10),
+                    },
+                    radius: random(4) + 4,
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = (0, Reverse(0));
+            for x in -18..=18 {
+                for y in -18..=18 {
+                    for z in -18..=18 {
+                        let point = Point { x, y, z };
+                        let count = nanobots.iter().filter(|n| n.in_range(&point)).count();
+                        expected = expected.max((count, Reverse(point.distance(&ORIGIN))));
+                    }
+                }
+            }
+
+            let position = best_position(&nanobots);
+            let count = nanobots.iter().filter(|n| n.in_range(&position)).count();
 
-        assert_eq!(answer, solve_second_part(INPUT))
+            assert_eq!(expected, (count, Reverse(position.distance(&ORIGIN))));
+        }
     }
 
     // check_answers!(42, 42);
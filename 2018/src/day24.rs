@@ -1,4 +1,193 @@
-use std::fmt::Display;
+use std::{cmp::Reverse, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Army {
+    ImmuneSystem,
+    Infection,
+}
+
+#[derive(Debug, Clone)]
+struct Group {
+    army: Army,
+    units: usize,
+    hit_points: usize,
+    weaknesses: Vec<String>,
+    immunities: Vec<String>,
+    damage: usize,
+    attack_type: String,
+    initiative: usize,
+}
+
+impl Group {
+    fn parse(value: &str, army: Army) -> Self {
+        let words = value.split_ascii_whitespace().collect::<Vec<_>>();
+        let mut weaknesses = Vec::new();
+        let mut immunities = Vec::new();
+
+        if let Some((_, traits)) = value.split_once('(') {
+            let (traits, _) = traits.split_once(')').unwrap();
+
+            for part in traits.split("; ") {
+                if let Some(types) = part.strip_prefix("weak to ") {
+                    weaknesses.extend(types.split(", ").map(String::from));
+                } else if let Some(types) = part.strip_prefix("immune to ") {
+                    immunities.extend(types.split(", ").map(String::from));
+                } else {
+                    panic!("Unexpected traits: {part}");
+                }
+            }
+        }
+
+        let attack = words.iter().position(|w| *w == "does").unwrap();
+
+        Self {
+            army,
+            units: words[0].parse().unwrap(),
+            hit_points: words[4].parse().unwrap(),
+            weaknesses,
+            immunities,
+            damage: words[attack + 1].parse().unwrap(),
+            attack_type: words[attack + 2].to_string(),
+            initiative: words.last().unwrap().parse().unwrap(),
+        }
+    }
+
+    fn effective_power(&self) -> usize {
+        self.units * self.damage
+    }
+
+    fn damage_to(&self, other: &Group) -> usize {
+        if other.immunities.contains(&self.attack_type) {
+            0
+        } else if other.weaknesses.contains(&self.attack_type) {
+            self.effective_power() * 2
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Victory(Army, usize),
+    Stalemate,
+}
+
+#[derive(Debug, Clone)]
+struct Battle {
+    groups: Vec<Group>,
+}
+
+impl From<&str> for Battle {
+    fn from(value: &str) -> Self {
+        let (immune_system, infection) = value.split_once("\n\n").unwrap();
+
+        let groups = immune_system
+            .lines()
+            .skip(1)
+            .map(|line| Group::parse(line, Army::ImmuneSystem))
+            .chain(
+                infection
+                    .lines()
+                    .skip(1)
+                    .map(|line| Group::parse(line, Army::Infection)),
+            )
+            .collect();
+
+        Self { groups }
+    }
+}
+
+impl Battle {
+    fn boosted(mut self, boost: usize) -> Self {
+        self.groups
+            .iter_mut()
+            .filter(|g| g.army == Army::ImmuneSystem)
+            .for_each(|g| g.damage += boost);
+        self
+    }
+
+    fn fight(&mut self) -> Outcome {
+        loop {
+            let armies = self.groups.iter().map(|g| g.army);
+            if !armies.clone().any(|a| a == Army::ImmuneSystem)
+                || !armies.clone().any(|a| a == Army::Infection)
+            {
+                let army = self.groups[0].army;
+                return Outcome::Victory(army, self.groups.iter().map(|g| g.units).sum());
+            }
+
+            if !self.round() {
+                return Outcome::Stalemate;
+            }
+        }
+    }
+
+    fn round(&mut self) -> bool {
+        let targets = self.select_targets();
+
+        let mut order = (0..self.groups.len()).collect::<Vec<_>>();
+        order.sort_by_key(|i| Reverse(self.groups[*i].initiative));
+
+        let mut killed_any = false;
+        for attacker in order {
+            let Some(defender) = targets[attacker] else {
+                continue;
+            };
+            if self.groups[attacker].units == 0 {
+                continue;
+            }
+
+            let damage = self.groups[attacker].damage_to(&self.groups[defender]);
+            let defender = &mut self.groups[defender];
+            let killed = (damage / defender.hit_points).min(defender.units);
+
+            defender.units -= killed;
+            killed_any |= killed > 0;
+        }
+
+        self.groups.retain(|g| g.units > 0);
+
+        killed_any
+    }
+
+    fn select_targets(&self) -> Vec<Option<usize>> {
+        let mut order = (0..self.groups.len()).collect::<Vec<_>>();
+        order.sort_by_key(|i| {
+            let group = &self.groups[*i];
+            Reverse((group.effective_power(), group.initiative))
+        });
+
+        let mut targets = vec![None; self.groups.len()];
+        let mut chosen = vec![false; self.groups.len()];
+
+        for attacker in order {
+            let group = &self.groups[attacker];
+
+            let target = self
+                .groups
+                .iter()
+                .enumerate()
+                .filter(|(i, other)| other.army != group.army && !chosen[*i])
+                .filter(|(_, other)| group.damage_to(other) > 0)
+                .max_by_key(|(_, other)| {
+                    (
+                        group.damage_to(other),
+                        other.effective_power(),
+                        other.initiative,
+                    )
+                })
+                .map(|(i, _)| i);
+
+            if let Some(target) = target {
+                chosen[target] = true;
+            }
+            targets[attacker] = target;
+        }
+
+        targets
+    }
+}
 
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
@@ -7,36 +196,94 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    match Battle::from(input).fight() {
+        Outcome::Victory(_, units) => units,
+        Outcome::Stalemate => panic!("The battle ended in a stalemate"),
+    }
 }
 
-fn solve_second_part(_input: &str) -> i32 {
-    0
+fn solve_second_part(input: &str) -> usize {
+    let battle = Battle::from(input);
+
+    (1..)
+        .find_map(|boost| match battle.clone().boosted(boost).fight() {
+            Outcome::Victory(Army::ImmuneSystem, units) => Some(units),
+            _ => None,
+        })
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = "Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 5216;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
     fn test_second_part() {
-        let answer = 42;
+        let answer = 51;
 
         assert_eq!(answer, solve_second_part(INPUT))
     }
 
+    #[test]
+    fn test_outcomes() {
+        assert_eq!(
+            Battle::from(INPUT).fight(),
+            Outcome::Victory(Army::Infection, 5216)
+        );
+        assert_eq!(
+            Battle::from(INPUT).boosted(1570).fight(),
+            Outcome::Victory(Army::ImmuneSystem, 51)
+        );
+    }
+
+    #[test]
+    fn test_stalemate() {
+        let input = "Immune System:
+10 units each with 100 hit points (immune to fire) with an attack that does 5 cold damage at initiative 2
+
+Infection:
+10 units each with 100 hit points (immune to cold) with an attack that does 5 fire damage at initiative 1
+";
+
+        assert_eq!(Battle::from(input).fight(), Outcome::Stalemate);
+    }
+
+    #[test]
+    fn test_parse_traits_in_any_order() {
+        let group = Group::parse(
+            "10 units each with 20 hit points (immune to cold, fire; weak to slashing) with an attack that does 3 radiation damage at initiative 5",
+            Army::Infection,
+        );
+
+        assert_eq!(group.immunities, vec!["cold", "fire"]);
+        assert_eq!(group.weaknesses, vec!["slashing"]);
+        assert_eq!(group.damage, 3);
+        assert_eq!(group.attack_type, "radiation");
+        assert_eq!(group.initiative, 5);
+
+        let group = Group::parse(
+            "10 units each with 20 hit points with an attack that does 3 fire damage at initiative 5",
+            Army::ImmuneSystem,
+        );
+        assert!(group.immunities.is_empty() && group.weaknesses.is_empty());
+    }
+
     // check_answers!(42, 42);
 }
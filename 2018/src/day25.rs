@@ -1,5 +1,30 @@
 use std::fmt::Display;
 
+use crate::shared::union_find::UnionFind;
+
+const CONSTELLATION_DISTANCE: i32 = 3;
+
+#[derive(Debug, Clone, Copy)]
+struct Point([i32; 4]);
+
+impl From<&str> for Point {
+    fn from(value: &str) -> Self {
+        let mut coordinates = value.trim().split(',').map(|c| c.parse().unwrap());
+
+        Self([(); 4].map(|_| coordinates.next().unwrap()))
+    }
+}
+
+impl Point {
+    fn distance(&self, other: &Point) -> i32 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum()
+    }
+}
+
 pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     (
         Box::new(solve_first_part(input)),
@@ -7,8 +32,19 @@ pub fn solve(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
     )
 }
 
-fn solve_first_part(_input: &str) -> i32 {
-    0
+fn solve_first_part(input: &str) -> usize {
+    let points = input.lines().map(Point::from).collect::<Vec<_>>();
+    let mut constellations = UnionFind::new(points.len());
+
+    for (i, point) in points.iter().enumerate() {
+        for (j, other) in points.iter().enumerate().skip(i + 1) {
+            if point.distance(other) <= CONSTELLATION_DISTANCE {
+                constellations.union(i, j);
+            }
+        }
+    }
+
+    constellations.components()
 }
 
 fn solve_second_part(_input: &str) -> i32 {
@@ -19,23 +55,63 @@ fn solve_second_part(_input: &str) -> i32 {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
+    const INPUT: &str = " 0,0,0,0
+ 3,0,0,0
+ 0,3,0,0
+ 0,0,3,0
+ 0,0,0,3
+ 0,0,0,6
+ 9,0,0,0
+12,0,0,0
 ";
 
-    #[ignore]
     #[test]
     fn test_first_part() {
-        let answer = 42;
+        let answer = 2;
 
         assert_eq!(answer, solve_first_part(INPUT))
     }
 
-    #[ignore]
     #[test]
-    fn test_second_part() {
-        let answer = 42;
+    fn test_constellations() {
+        let input = "-1,2,2,0
+0,0,2,-2
+0,0,0,-2
+-1,2,0,0
+-2,-2,-2,2
+3,0,2,-1
+-1,3,2,2
+-1,0,-1,0
+0,2,1,-2
+3,0,0,0
+";
+        assert_eq!(solve_first_part(input), 4);
+
+        let input = "1,-1,0,1
+2,0,-1,0
+3,2,-1,0
+0,0,3,1
+0,0,-1,-1
+2,3,-2,0
+-2,2,0,0
+2,-2,0,-1
+1,-1,0,-1
+3,2,0,2
+";
+        assert_eq!(solve_first_part(input), 3);
 
-        assert_eq!(answer, solve_second_part(INPUT))
+        let input = "1,-1,-1,-2
+-2,-2,0,1
+0,2,1,3
+-2,3,-2,1
+0,2,3,-2
+-1,-1,1,-2
+0,-2,-1,0
+-2,2,3,-1
+1,2,2,0
+-1,-2,0,-2
+";
+        assert_eq!(solve_first_part(input), 8);
     }
 
     // check_answers!(42, 42);
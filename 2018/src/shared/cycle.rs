@@ -0,0 +1,150 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    pub fn find<S, F>(initial: &S, mut step: F) -> Self
+    where
+        S: Clone + PartialEq,
+        F: FnMut(&mut S),
+    {
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = initial.clone();
+        step(&mut hare);
+
+        while tortoise != hare {
+            if power == length {
+                tortoise.clone_from(&hare);
+                power *= 2;
+                length = 0;
+            }
+            step(&mut hare);
+            length += 1;
+        }
+
+        let mut prefix = 0;
+        tortoise.clone_from(initial);
+        hare.clone_from(initial);
+        for _ in 0..length {
+            step(&mut hare);
+        }
+
+        while tortoise != hare {
+            step(&mut tortoise);
+            step(&mut hare);
+            prefix += 1;
+        }
+
+        Self { prefix, length }
+    }
+
+    #[allow(dead_code)]
+    pub fn find_by_key<S, K, F, G>(initial: &S, mut step: F, key: G) -> Self
+    where
+        S: Clone,
+        K: Hash + Eq,
+        F: FnMut(&mut S),
+        G: Fn(&S) -> K,
+    {
+        let mut seen = HashMap::new();
+        let mut state = initial.clone();
+
+        for index in 0.. {
+            if let Some(first) = seen.insert(key(&state), index) {
+                return Self {
+                    prefix: first,
+                    length: index - first,
+                };
+            }
+            step(&mut state);
+        }
+
+        unreachable!()
+    }
+
+    pub fn equivalent_index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+
+    pub fn state_at<S, F>(&self, initial: &S, mut step: F, n: usize) -> S
+    where
+        S: Clone,
+        F: FnMut(&mut S),
+    {
+        let mut state = initial.clone();
+        for _ in 0..self.equivalent_index(n) {
+            step(&mut state);
+        }
+
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &mut u64) {
+        *x = (*x * *x + 1) % 255;
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(
+            Cycle::find(&3, step),
+            Cycle {
+                prefix: 2,
+                length: 6
+            }
+        );
+        assert_eq!(
+            Cycle::find(&0, |x: &mut u8| *x = (*x + 1) % 5),
+            Cycle {
+                prefix: 0,
+                length: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        assert_eq!(Cycle::find_by_key(&3, step, |x| *x), Cycle::find(&3, step));
+        assert_eq!(
+            Cycle::find_by_key(
+                &(0, 0),
+                |(x, n): &mut (u8, u8)| {
+                    *x = (*x + 1) % 4;
+                    *n += 1;
+                },
+                |(x, _)| *x
+            ),
+            Cycle {
+                prefix: 0,
+                length: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_state_at() {
+        let cycle = Cycle::find(&3, step);
+        let mut expected = 3;
+        for _ in 0..1000 {
+            step(&mut expected);
+        }
+
+        assert_eq!(cycle.state_at(&3, step, 1000), expected);
+        assert_eq!(cycle.state_at(&3, step, 1), 10);
+        assert_eq!(cycle.equivalent_index(1_000_000_000), 2 + 999_999_998 % 6);
+    }
+}
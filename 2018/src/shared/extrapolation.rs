@@ -0,0 +1,62 @@
+pub fn extrapolate_steady_state<S, K, F, G, V>(
+    initial: S,
+    target: usize,
+    mut step: F,
+    key: G,
+    value: V,
+) -> i64
+where
+    K: PartialEq,
+    F: FnMut(&S) -> S,
+    G: Fn(&S) -> K,
+    V: Fn(&S) -> i64,
+{
+    let mut state = initial;
+
+    for generation in 0..target {
+        let next = step(&state);
+
+        if key(&next) == key(&state) {
+            let delta = value(&next) - value(&state);
+            let remaining = (target - generation - 1) as i64;
+
+            return value(&next) + delta * remaining;
+        }
+
+        state = next;
+    }
+
+    value(&state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate_steady_state() {
+        let shifting = |state: &(Vec<u8>, i64)| (state.0.clone(), state.1 + 3);
+        assert_eq!(
+            extrapolate_steady_state(
+                (vec![1, 2], 5),
+                1_000_000,
+                shifting,
+                |s| s.0.clone(),
+                |s| s.1
+            ),
+            3_000_005
+        );
+
+        let growing = |state: &u64| if *state < 10 { state + 1 } else { *state };
+        assert_eq!(
+            extrapolate_steady_state(0, 1_000_000, growing, |s| *s, |s| *s as i64),
+            10
+        );
+
+        let counting = |state: &i64| state + 1;
+        assert_eq!(
+            extrapolate_steady_state(0, 100, counting, |s| *s, |s| *s),
+            100
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod cycle;
+pub mod extrapolation;
+#[cfg(test)]
+pub mod random;
+pub mod rectangles;
+pub mod union_find;
@@ -0,0 +1,222 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Rectangle {
+    fn right(&self) -> i64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> i64 {
+        self.y + self.height
+    }
+
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.x < other.right()
+            && other.x < self.right()
+            && self.y < other.bottom()
+            && other.y < self.bottom()
+    }
+}
+
+/// Segment tree over compressed y coordinates that tracks how much of the
+/// sweep line is covered by at least one and at least two active rectangles.
+struct Coverage {
+    ys: Vec<i64>,
+    count: Vec<i32>,
+    once: Vec<i64>,
+    twice: Vec<i64>,
+}
+
+impl Coverage {
+    fn new(ys: Vec<i64>) -> Self {
+        let nodes = 4 * ys.len().max(1);
+
+        Self {
+            ys,
+            count: vec![0; nodes],
+            once: vec![0; nodes],
+            twice: vec![0; nodes],
+        }
+    }
+
+    fn add(&mut self, from: i64, to: i64, delta: i32) {
+        let from = self.ys.partition_point(|y| *y < from);
+        let to = self.ys.partition_point(|y| *y < to);
+
+        self.update(1, 0, self.ys.len() - 1, from, to, delta);
+    }
+
+    fn update(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, delta: i32) {
+        if to <= lo || hi <= from {
+            return;
+        }
+
+        if from <= lo && hi <= to {
+            self.count[node] += delta;
+        } else {
+            let mid = (lo + hi) / 2;
+            self.update(2 * node, lo, mid, from, to, delta);
+            self.update(2 * node + 1, mid, hi, from, to, delta);
+        }
+
+        self.pull(node, lo, hi);
+    }
+
+    fn pull(&mut self, node: usize, lo: usize, hi: usize) {
+        let full = self.ys[hi] - self.ys[lo];
+        let leaf = hi - lo == 1;
+        let (children_once, children_twice) = if leaf {
+            (0, 0)
+        } else {
+            (
+                self.once[2 * node] + self.once[2 * node + 1],
+                self.twice[2 * node] + self.twice[2 * node + 1],
+            )
+        };
+
+        (self.once[node], self.twice[node]) = match self.count[node] {
+            0 => (children_once, children_twice),
+            1 => (full, children_once),
+            _ => (full, full),
+        };
+    }
+
+    fn twice(&self) -> i64 {
+        self.twice[1]
+    }
+}
+
+pub fn overlap_area(rectangles: &[Rectangle]) -> i64 {
+    let rectangles = rectangles
+        .iter()
+        .filter(|r| r.width > 0 && r.height > 0)
+        .collect::<Vec<_>>();
+    if rectangles.is_empty() {
+        return 0;
+    }
+
+    let mut ys = rectangles
+        .iter()
+        .flat_map(|r| [r.y, r.bottom()])
+        .collect::<Vec<_>>();
+    ys.sort_unstable();
+    ys.dedup();
+
+    let mut events = rectangles
+        .iter()
+        .flat_map(|r| [(r.x, 1, r.y, r.bottom()), (r.right(), -1, r.y, r.bottom())])
+        .collect::<Vec<_>>();
+    events.sort_unstable();
+
+    let mut coverage = Coverage::new(ys);
+    let mut area = 0;
+    let mut previous = events[0].0;
+
+    for (x, delta, from, to) in events {
+        area += coverage.twice() * (x - previous);
+        coverage.add(from, to, delta);
+        previous = x;
+    }
+
+    area
+}
+
+pub fn isolated(rectangles: &[Rectangle]) -> Vec<usize> {
+    let mut order = (0..rectangles.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| rectangles[*i].x);
+
+    let mut overlapping = vec![false; rectangles.len()];
+    let mut active: Vec<usize> = Vec::new();
+
+    for index in order {
+        let rectangle = &rectangles[index];
+        active.retain(|other| rectangles[*other].right() > rectangle.x);
+
+        for other in &active {
+            if rectangle.intersects(&rectangles[*other]) {
+                overlapping[index] = true;
+                overlapping[*other] = true;
+            }
+        }
+
+        active.push(index);
+    }
+
+    (0..rectangles.len()).filter(|i| !overlapping[*i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rectangle(x: i64, y: i64, width: i64, height: i64) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_overlap_area() {
+        let rectangles = [
+            rectangle(1, 3, 4, 4),
+            rectangle(3, 1, 4, 4),
+            rectangle(5, 5, 2, 2),
+        ];
+        assert_eq!(overlap_area(&rectangles), 4);
+
+        let rectangles = [
+            rectangle(0, 0, 10, 10),
+            rectangle(5, 5, 10, 10),
+            rectangle(0, 0, 10, 10),
+        ];
+        assert_eq!(overlap_area(&rectangles), 100);
+
+        let rectangles = [
+            rectangle(-1_000_000_000, 0, 2_000_000_000, 3),
+            rectangle(0, -5_000_000, 1_000_000, 10_000_000),
+        ];
+        assert_eq!(overlap_area(&rectangles), 3_000_000);
+
+        assert_eq!(overlap_area(&[]), 0);
+        assert_eq!(overlap_area(&[rectangle(0, 0, 5, 5)]), 0);
+    }
+
+    #[test]
+    fn test_overlap_area_matches_grid() {
+        let rectangles = (0..60)
+            .map(|i| rectangle(i * 7 % 23, i * 11 % 19, 1 + i % 6, 1 + i * 5 % 7))
+            .collect::<Vec<_>>();
+
+        let mut grid = vec![vec![0; 40]; 40];
+        for r in &rectangles {
+            for row in &mut grid[r.y as usize..r.bottom() as usize] {
+                for cell in &mut row[r.x as usize..r.right() as usize] {
+                    *cell += 1;
+                }
+            }
+        }
+        let expected = grid.iter().flatten().filter(|c| **c > 1).count() as i64;
+
+        assert_eq!(overlap_area(&rectangles), expected);
+    }
+
+    #[test]
+    fn test_isolated() {
+        let rectangles = [
+            rectangle(1, 3, 4, 4),
+            rectangle(3, 1, 4, 4),
+            rectangle(5, 5, 2, 2),
+            rectangle(100_000, 100_000, 1, 1),
+        ];
+
+        assert_eq!(isolated(&rectangles), vec![2, 3]);
+    }
+}
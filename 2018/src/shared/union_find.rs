@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+            ranks: vec![0; size],
+            components: size,
+        }
+    }
+
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+
+        let (child, parent) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[child] = parent;
+        if self.ranks[child] == self.ranks[parent] {
+            self.ranks[parent] += 1;
+        }
+        self.components -= 1;
+
+        true
+    }
+
+    #[allow(dead_code)]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    #[allow(dead_code)]
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for element in 0..self.parents.len() {
+            groups.entry(self.find(element)).or_default().push(element);
+        }
+
+        let mut groups = groups.into_values().collect::<Vec<_>>();
+        groups.sort();
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.components(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.groups(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+}